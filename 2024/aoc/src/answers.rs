@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use utils::solution::{Answer, Entry, Part};
+
+/// Name of the manifest with the answers of a year, which lives next to the
+/// crates of the days.
+pub const FILENAME: &str = "answers.toml";
+
+/// The known answers of a year.
+///
+/// ```toml
+/// [day17]
+/// part1 = "7,4,2,0,5,0,5,3,7"
+/// part2 = 202991746427434
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    answers: BTreeMap<(u8, Part), Answer>,
+}
+
+impl Answers {
+    /// Returns the path of the manifest of the year the entry belongs to.
+    pub fn path(entry: &Entry) -> PathBuf {
+        Path::new(entry.dir)
+            .parent()
+            .unwrap_or(Path::new(entry.dir))
+            .join(FILENAME)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let manifest = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read file {}: {}", path.display(), err))?;
+        manifest
+            .parse()
+            .map_err(|err| format!("Failed to parse {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&Answer> {
+        self.answers.get(&(day, part))
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: toml::Table = s.parse().map_err(|err: toml::de::Error| err.to_string())?;
+        let mut answers = BTreeMap::new();
+
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("{} is not a day, expected e.g. day01", key))?;
+            let parts = value
+                .as_table()
+                .ok_or_else(|| format!("{} is not a table", key))?;
+
+            for (name, answer) in parts {
+                let part = match name.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("{}.{} is not a part", key, name)),
+                };
+                let answer = match answer {
+                    toml::Value::Integer(n) => Answer::Int(*n as i128),
+                    toml::Value::String(s) => Answer::Text(s.clone()),
+                    _ => return Err(format!("{}.{} is neither a number nor a string", key, name)),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Self { answers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let answers: Answers = "[day17]\npart1 = \"7,4,2\"\npart2 = 202991746427434\n"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            answers.get(17, Part::One),
+            Some(&Answer::Text("7,4,2".to_string()))
+        );
+        assert_eq!(
+            answers.get(17, Part::Two),
+            Some(&Answer::Int(202991746427434))
+        );
+        assert_eq!(answers.get(1, Part::One), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            "[d1]\npart1 = 1".parse::<Answers>(),
+            Err("d1 is not a day, expected e.g. day01".to_string())
+        );
+        assert_eq!(
+            "[day01]\npart3 = 1".parse::<Answers>(),
+            Err("day01.part3 is not a part".to_string())
+        );
+        assert_eq!(
+            "[day01]\npart1 = 1.5".parse::<Answers>(),
+            Err("day01.part1 is neither a number nor a string".to_string())
+        );
+    }
+
+    #[test]
+    fn year_manifest_is_valid() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(FILENAME);
+        let answers = Answers::load(&path).unwrap();
+        assert_eq!(answers.get(1, Part::One), Some(&Answer::Int(2756096)));
+        assert_eq!(
+            answers.get(24, Part::Two),
+            Some(&Answer::Text("bpt,fkp,krj,mfm,ngr,z06,z11,z31".to_string()))
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use crate::isolate;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use utils::{bench::Stats, fetch, solution::Entry};
+
+pub use utils::bench::Config;
+
+/// Slowdowns of less than this are noise, however large they are relative to
+/// the previous run.
+const MIN_REGRESSION: Duration = Duration::from_micros(50);
+
+/// All benchmark runs of a year, oldest first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct History {
+    runs: Vec<Run>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Run {
+    /// Seconds since the Unix epoch.
+    timestamp: u64,
+    records: Vec<Record>,
+}
+
+/// Statistics of a single stage of a day, in nanoseconds.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Record {
+    day: u8,
+    stage: String,
+    runs: usize,
+    min: u64,
+    max: u64,
+    mean: u64,
+    median: u64,
+    stddev: u64,
+}
+
+impl Record {
+    fn new(day: u8, stage: String, stats: &Stats) -> Self {
+        let nanos = |duration: Duration| duration.as_nanos() as u64;
+        Self {
+            day,
+            stage,
+            runs: stats.runs,
+            min: nanos(stats.min),
+            max: nanos(stats.max),
+            mean: nanos(stats.mean),
+            median: nanos(stats.median),
+            stddev: nanos(stats.stddev),
+        }
+    }
+
+    /// Returns the relative change of the median compared to a previous
+    /// record, and whether that is a regression.
+    fn compare(&self, previous: &Record, threshold: f64) -> (f64, bool) {
+        let change = self.median as f64 / previous.median.max(1) as f64 - 1.0;
+        let slower = Duration::from_nanos(self.median.saturating_sub(previous.median));
+        (change, change > threshold && slower >= MIN_REGRESSION)
+    }
+}
+
+impl History {
+    fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(json) => serde_json::from_str(&json)
+                .map_err(|err| format!("Failed to parse {}: {}", path.display(), err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("Failed to read file {}: {}", path.display(), err)),
+        }
+    }
+
+    fn save(&self, path: &Path) -> Result<(), String> {
+        let write = || -> io::Result<()> {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+        };
+        write().map_err(|err| format!("Failed to write file {}: {}", path.display(), err))
+    }
+
+    /// Returns the latest record of a stage.
+    fn previous(&self, day: u8, stage: &str) -> Option<&Record> {
+        self.runs
+            .iter()
+            .rev()
+            .flat_map(|run| &run.records)
+            .find(|record| record.day == day && record.stage == stage)
+    }
+}
+
+/// Returns the default location of the history of a year, in the download
+/// cache.
+pub fn history_path(year: u16) -> PathBuf {
+    fetch::cache_dir_from_env()
+        .join("bench")
+        .join(format!("{}.json", year))
+}
+
+/// Times parsing and both parts of every day, prints the statistics and
+/// flags the stages that got slower than `threshold` (e.g. 0.1 for 10%)
+/// since their previous run. The parts are timed exactly as they run, with
+/// stdout left alone, and a panicking day is reported and skipped.
+pub fn bench(entries: Vec<&Entry>, config: &Config, threshold: f64, history: &Path) -> ExitCode {
+    let mut past = match History::load(history) {
+        Ok(past) => past,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut records = Vec::new();
+    let mut regressions = 0;
+    let mut res = ExitCode::SUCCESS;
+
+    println!(
+        "{:>3}  {:<6}  {:>10}  {:>10}  {:>10}  {:>10}  {:>4}  {:>8}",
+        "day", "stage", "median", "mean", "stddev", "min", "runs", "change"
+    );
+    for entry in entries {
+        let input = match crate::read_input(entry, "input.txt", false) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{}", err);
+                res = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let timings = match isolate::run(|| entry.bench(&input, config)) {
+            Ok(timings) => timings,
+            Err(message) => {
+                eprintln!("day {} panicked: {}", entry.day, message);
+                res = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let stages = std::iter::once(("parse".to_string(), timings.parse)).chain(
+            timings
+                .parts
+                .iter()
+                .map(|(part, stats)| (format!("part{}", part), *stats)),
+        );
+
+        for (stage, stats) in stages {
+            let record = Record::new(entry.day, stage, &stats);
+            let change = match past.previous(entry.day, &record.stage) {
+                Some(previous) => {
+                    let (change, regression) = record.compare(previous, threshold);
+                    regressions += regression as usize;
+                    format!(
+                        "{:+.1}%{}",
+                        change * 100.0,
+                        if regression { " REGRESSION" } else { "" }
+                    )
+                }
+                None => "new".to_string(),
+            };
+            println!(
+                "{:>3}  {:<6}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {:>10.2?}  {:>4}  {:>8}",
+                entry.day,
+                record.stage,
+                stats.median,
+                stats.mean,
+                stats.stddev,
+                stats.min,
+                stats.runs,
+                change
+            );
+            records.push(record);
+        }
+    }
+
+    if regressions > 0 {
+        println!("{} stages got slower since the previous run", regressions);
+        res = ExitCode::FAILURE;
+    }
+    past.runs.push(Run {
+        timestamp: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs()),
+        records,
+    });
+    if let Err(err) = past.save(history) {
+        eprintln!("{}", err);
+        res = ExitCode::FAILURE;
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(day: u8, stage: &str, median: u64) -> Record {
+        Record {
+            day,
+            stage: stage.to_string(),
+            runs: 10,
+            min: median,
+            max: median,
+            mean: median,
+            median,
+            stddev: 0,
+        }
+    }
+
+    #[test]
+    fn compare() {
+        let previous = record(6, "part2", 1_000_000);
+        let (change, regression) = record(6, "part2", 1_200_000).compare(&previous, 0.1);
+        assert!((change - 0.2).abs() < 1e-9);
+        assert!(regression);
+        assert!(!record(6, "part2", 1_050_000).compare(&previous, 0.1).1);
+        assert!(record(6, "part2", 500_000).compare(&previous, 0.1).0 < 0.0);
+        // Twice as slow, but only by a few microseconds.
+        assert_eq!(
+            record(6, "parse", 4_000).compare(&record(6, "parse", 2_000), 0.1),
+            (1.0, false)
+        );
+    }
+
+    #[test]
+    fn history_roundtrip() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-bench-{}", std::process::id()))
+            .join("2024.json");
+        let _ = fs::remove_file(&path);
+
+        let mut history = History::load(&path).unwrap();
+        assert!(history.runs.is_empty());
+        for (timestamp, median) in [(1, 100), (2, 200)] {
+            history.runs.push(Run {
+                timestamp,
+                records: vec![record(1, "part1", median)],
+            });
+        }
+        history.save(&path).unwrap();
+
+        let history = History::load(&path).unwrap();
+        assert_eq!(history.runs.len(), 2);
+        assert_eq!(history.previous(1, "part1").unwrap().median, 200);
+        assert!(history.previous(1, "part2").is_none());
+    }
+}
@@ -0,0 +1,148 @@
+use crate::http::UreqClient;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use utils::{fetch::Fetcher, puzzle::Puzzle, solution::Part, Error, Result};
+
+/// Name of the manifest with the expected answers of the examples.
+pub const MANIFEST: &str = "examples.toml";
+
+/// Extracts the examples of a day from its cached description page and writes
+/// them together with the manifest into `out`, or the cache directory of the
+/// day.
+pub fn examples(year: u16, day: u8, out: Option<PathBuf>, refresh: bool, tests: bool) -> ExitCode {
+    match extract(year, day, out, refresh, tests) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => {
+            eprintln!("No puzzle description of day {} found!", day);
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn extract(year: u16, day: u8, out: Option<PathBuf>, refresh: bool, tests: bool) -> Result<bool> {
+    let fetcher = Fetcher::from_env(UreqClient::new());
+    let page = fetcher.puzzle_path(year, day);
+    if refresh {
+        match fs::remove_file(&page) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                return Err(Error::Io {
+                    path: page,
+                    source: err,
+                })
+            }
+            _ => {}
+        }
+    }
+
+    let puzzle = Puzzle::parse(&fetcher.puzzle(year, day)?);
+    if puzzle.parts.is_empty() {
+        return Ok(false);
+    }
+    let out = out.unwrap_or_else(|| fetcher.day_dir(year, day));
+    for example in puzzle.examples() {
+        write(&out.join(&example.file), &(example.input + "\n"))?;
+    }
+    write(&out.join(MANIFEST), &puzzle.manifest())?;
+
+    if tests {
+        print!("{}", render_tests(&puzzle, day));
+    }
+    Ok(true)
+}
+
+fn write(path: &Path, contents: &str) -> Result<()> {
+    let io_error = |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)?;
+    println!("{}", path.display());
+    Ok(())
+}
+
+/// Renders the example tests of the parts that have an expected answer, in
+/// the layout of the tests of the days.
+fn render_tests(puzzle: &Puzzle, day: u8) -> String {
+    let mut tests =
+        String::from("#[cfg(test)]\nmod tests {\n    use super::*;\n    use utils::input;\n");
+    for (part, description) in Part::ALL.into_iter().zip(&puzzle.parts) {
+        let (Some(example), Some(answer)) = (puzzle.example_for(part), &description.answer) else {
+            continue;
+        };
+        let answer = match answer.parse::<i128>() {
+            Ok(_) => answer.clone(),
+            Err(_) => format!("{:?}", answer),
+        };
+        let _ = write!(
+            tests,
+            "
+    mod exercise{part} {{
+        use super::*;
+
+        #[test]
+        fn example() {{
+            let input = input::read_file({file:?});
+            let res = exercise{part}(&Day{day:02}::parse(&input));
+            assert_eq!(res, {answer});
+        }}
+    }}
+",
+            part = part,
+            day = day,
+            file = example.file,
+            answer = answer,
+        );
+    }
+    tests + "}\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::puzzle::Description;
+
+    #[test]
+    fn tests_of_both_parts() {
+        let puzzle = Puzzle {
+            parts: vec![
+                Description {
+                    examples: vec!["3   4".to_string()],
+                    answer: Some("11".to_string()),
+                },
+                Description {
+                    examples: vec![],
+                    answer: Some("4,6,3".to_string()),
+                },
+            ],
+        };
+        let tests = render_tests(&puzzle, 1);
+
+        assert!(tests.starts_with("#[cfg(test)]\nmod tests {\n    use super::*;\n"));
+        assert!(tests.contains("    mod exercise1 {\n        use super::*;\n\n        #[test]\n        fn example() {\n            let input = input::read_file(\"example.txt\");\n            let res = exercise1(&Day01::parse(&input));\n            assert_eq!(res, 11);\n"));
+        assert!(tests.contains(
+            "let res = exercise2(&Day01::parse(&input));\n            assert_eq!(res, \"4,6,3\");"
+        ));
+        assert!(tests.ends_with("    }\n}\n"));
+    }
+
+    #[test]
+    fn tests_without_answer() {
+        let puzzle = Puzzle {
+            parts: vec![Description {
+                examples: vec!["3   4".to_string()],
+                answer: None,
+            }],
+        };
+        assert!(!render_tests(&puzzle, 1).contains("mod exercise1"));
+    }
+}
@@ -0,0 +1,149 @@
+use utils::{
+    fetch::{HttpClient, Response},
+    Error, Result,
+};
+
+const USER_AGENT: &str = concat!("aoc-runner/", env!("CARGO_PKG_VERSION"), " (ureq)");
+
+/// [`HttpClient`] that talks to the real site.
+pub struct UreqClient {
+    agent: ureq::Agent,
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Self { agent }
+    }
+}
+
+impl HttpClient for UreqClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        let http_error = |err: ureq::Error| Error::Http {
+            url: url.to_string(),
+            message: err.to_string(),
+        };
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", format!("session={}", session))
+            .call()
+            .map_err(http_error)?;
+
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string().map_err(http_error)?,
+        })
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let http_error = |err: ureq::Error| Error::Http {
+            url: url.to_string(),
+            message: err.to_string(),
+        };
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Cookie", format!("session={}", session))
+            .send_form(form.iter().copied())
+            .map_err(http_error)?;
+
+        Ok(Response {
+            status: response.status().as_u16(),
+            body: response.body_mut().read_to_string().map_err(http_error)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single request and returns the base URL and the request head
+    /// and body.
+    fn stub_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(&stream);
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            let len = head
+                .lines()
+                .find_map(|line| {
+                    line.to_lowercase()
+                        .strip_prefix("content-length: ")?
+                        .parse()
+                        .ok()
+                })
+                .unwrap_or(0);
+            let mut content = vec![0; len];
+            reader.read_exact(&mut content).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            (head, String::from_utf8(content).unwrap())
+        });
+        (url, handle)
+    }
+
+    #[test]
+    fn get_sends_session() {
+        let (url, server) = stub_server("200 OK", "1 2\n");
+        let response = UreqClient::new()
+            .get(&format!("{}/2024/day/1/input", url), "abc")
+            .unwrap();
+        let (head, _) = server.join().unwrap();
+
+        assert_eq!(response, Response::ok("1 2\n"));
+        assert!(head.starts_with("GET /2024/day/1/input HTTP/1.1"));
+        assert!(head.to_lowercase().contains("cookie: session=abc"));
+    }
+
+    #[test]
+    fn get_error_status() {
+        let (url, server) = stub_server("404 Not Found", "Not yet");
+        let response = UreqClient::new().get(&url, "abc").unwrap();
+        server.join().unwrap();
+
+        assert_eq!(response.status, 404);
+        assert_eq!(response.body, "Not yet");
+    }
+
+    #[test]
+    fn post_form() {
+        let (url, server) = stub_server("200 OK", "<article>That's the right answer!</article>");
+        let response = UreqClient::new()
+            .post(&url, "abc", &[("level", "1"), ("answer", "7,4,2")])
+            .unwrap();
+        let (head, body) = server.join().unwrap();
+
+        assert_eq!(response.body, "<article>That's the right answer!</article>");
+        assert!(head.starts_with("POST / HTTP/1.1"));
+        assert!(head.to_lowercase().contains("cookie: session=abc"));
+        assert_eq!(body, "level=1&answer=7%2C4%2C2");
+    }
+}
@@ -0,0 +1,36 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Runs a solution and returns the message of its panic, if it panics, so
+/// that the next day can still run.
+pub fn run<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| message(payload.as_ref()))
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics() {
+        assert_eq!(run(|| 11), Ok(11));
+        assert_eq!(
+            run(|| panic!("no robot")),
+            Err::<(), _>("no robot".to_string())
+        );
+        assert_eq!(
+            run(|| panic!("day {}", 15)),
+            Err::<(), _>("day 15".to_string())
+        );
+    }
+}
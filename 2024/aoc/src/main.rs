@@ -0,0 +1,306 @@
+use clap::{Parser, Subcommand};
+use http::UreqClient;
+use registry::REGISTRY;
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+use utils::{
+    fetch::Fetcher,
+    input::InputResolver,
+    solution::{Entry, Part},
+    Error,
+};
+
+mod answers;
+mod bench;
+mod examples;
+mod http;
+mod isolate;
+mod registry;
+mod submit;
+mod verify;
+
+/*
+    Runs the solutions of any subset of days from a single binary,
+    e.g. `aoc run 2024 16 --part 2 --input example1.txt`.
+
+    Inputs that are not next to a day are taken from the download cache,
+    see `aoc fetch`, which needs the session cookie in `AOC_SESSION`.
+    `AOC_BASE_URL` points `fetch` and `submit` at another server, e.g. a
+    local mock.
+    `aoc examples` extracts the examples and their answers from the cached
+    description of a day.
+
+    `aoc verify 2024` checks the solutions against the answers in
+    `2024/answers.toml`.
+
+    `aoc bench 2024` times the solutions and compares them to the previous
+    run, whose results are kept next to the download cache.
+
+    `aoc submit 2024 17 2` submits an answer, unless earlier guesses already
+    show that it is wrong or the site asked to wait before answering again.
+*/
+
+#[derive(Parser)]
+#[command(about = "Advent of Code runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of the given days
+    Run {
+        /// Year of the puzzles
+        year: u16,
+        /// Days to run, all days of the year if none are given
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+        /// Only run this part
+        #[arg(long, value_parser = parse_part)]
+        part: Option<Part>,
+        /// Input file inside the directory of each day, or a path to it
+        #[arg(long, default_value = "input.txt")]
+        input: String,
+        /// Download inputs that are neither next to a day nor cached
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Download the inputs of the given days into the cache
+    Fetch {
+        /// Year of the puzzles
+        year: u16,
+        /// Days to download, all days of the year if none are given
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+    },
+    /// Write the examples and expected answers from the description of a day
+    Examples {
+        /// Year of the puzzle
+        year: u16,
+        /// Day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Directory to write to instead of the cache directory of the day
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Download the description again, e.g. after part 2 was unlocked
+        #[arg(long)]
+        refresh: bool,
+        /// Print the example tests for the day
+        #[arg(long)]
+        tests: bool,
+    },
+    /// Check the solutions against the known answers
+    Verify {
+        /// Year of the puzzles
+        year: u16,
+        /// Days to check, all days of the year if none are given
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+        /// Manifest with the answers instead of answers.toml of the year
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of the given days
+    Bench {
+        /// Year of the puzzles
+        year: u16,
+        /// Days to time, all days of the year if none are given
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        days: Vec<u8>,
+        /// Runs before measuring
+        #[arg(long, default_value_t = 3)]
+        warmup: usize,
+        /// Measured runs
+        #[arg(long, default_value_t = 20)]
+        runs: usize,
+        /// Seconds after which a stage stops being measured
+        #[arg(long, default_value_t = 5.0)]
+        max_time: f64,
+        /// Percentage by which a stage may get slower before it is flagged
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        /// JSON file with the previous runs instead of the one in the cache
+        #[arg(long)]
+        history: Option<PathBuf>,
+    },
+    /// Submit the answer of a part
+    Submit {
+        /// Year of the puzzle
+        year: u16,
+        /// Day of the puzzle
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to submit
+        #[arg(value_parser = parse_part)]
+        part: Part,
+        /// Answer to submit instead of the one of the solution
+        #[arg(long)]
+        answer: Option<String>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run {
+            year,
+            days,
+            part,
+            input,
+            fetch,
+        } => run(year, days, part, &input, fetch),
+        Command::Fetch { year, days } => fetch(year, days),
+        Command::Examples {
+            year,
+            day,
+            out,
+            refresh,
+            tests,
+        } => examples::examples(year, day, out, refresh, tests),
+        Command::Verify {
+            year,
+            days,
+            answers,
+        } => match select(year, &days) {
+            Some(entries) => verify::verify(entries, answers),
+            None => ExitCode::FAILURE,
+        },
+        Command::Bench {
+            year,
+            days,
+            warmup,
+            runs,
+            max_time,
+            threshold,
+            history,
+        } => {
+            let config = bench::Config {
+                warmup,
+                runs,
+                max_time: Duration::from_secs_f64(max_time),
+            };
+            let history = history.unwrap_or_else(|| bench::history_path(year));
+            match select(year, &days) {
+                Some(entries) => bench::bench(entries, &config, threshold / 100.0, &history),
+                None => ExitCode::FAILURE,
+            }
+        }
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => match select(year, &[day]) {
+            Some(entries) => submit::submit(entries[0], part, answer),
+            None => ExitCode::FAILURE,
+        },
+    }
+}
+
+/// Returns the solutions of the given days, or of the whole year if no days
+/// are given. Reports every day without a solution.
+fn select(year: u16, days: &[u8]) -> Option<Vec<&'static Entry>> {
+    if days.is_empty() {
+        let entries: Vec<&Entry> = REGISTRY.year(year).collect();
+        if entries.is_empty() {
+            eprintln!("No solutions for {} found!", year);
+            return None;
+        }
+        return Some(entries);
+    }
+
+    let mut entries = Vec::new();
+    for &day in days {
+        match REGISTRY.get(year, day) {
+            Some(entry) => entries.push(entry),
+            None => eprintln!("No solution for day {} of {} found!", day, year),
+        }
+    }
+    (entries.len() == days.len()).then_some(entries)
+}
+
+fn run(year: u16, days: Vec<u8>, part: Option<Part>, filename: &str, fetch: bool) -> ExitCode {
+    let mut res = ExitCode::SUCCESS;
+    let parts: Vec<Part> = part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+    let entries: Vec<Option<&Entry>> = if days.is_empty() {
+        REGISTRY.year(year).map(Some).collect()
+    } else {
+        days.iter().map(|&day| REGISTRY.get(year, day)).collect()
+    };
+
+    if entries.is_empty() {
+        eprintln!("No solutions for {} found!", year);
+        return ExitCode::FAILURE;
+    }
+    for (i, entry) in entries.into_iter().enumerate() {
+        let Some(entry) = entry else {
+            eprintln!("No solution for day {} of {} found!", days[i], year);
+            res = ExitCode::FAILURE;
+            continue;
+        };
+        let input = match read_input(entry, filename, fetch) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("{}", err);
+                res = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        println!("day {}:", entry.day);
+        for (part, answer) in parts.iter().zip(entry.solve(&input, &parts)) {
+            println!("exercise {}: {}", part, answer);
+        }
+    }
+    res
+}
+
+fn fetch(year: u16, days: Vec<u8>) -> ExitCode {
+    let mut res = ExitCode::SUCCESS;
+    let days: Vec<u8> = if days.is_empty() {
+        REGISTRY.year(year).map(|entry| entry.day).collect()
+    } else {
+        days
+    };
+    let fetcher = Fetcher::from_env(UreqClient::new());
+
+    for day in days {
+        match fetcher.input(year, day) {
+            Ok(_) => println!("day {}: {}", day, fetcher.input_path(year, day).display()),
+            Err(err) => {
+                eprintln!("day {}: {}", day, err);
+                res = ExitCode::FAILURE;
+            }
+        }
+    }
+    res
+}
+
+/// Reads the input next to the day, falling back to the download cache for
+/// the puzzle input.
+fn read_input(entry: &Entry, filename: &str, fetch: bool) -> utils::Result<String> {
+    let res = InputResolver::from_env()
+        .with_manifest_dir(entry.dir)
+        .read(filename);
+
+    match res {
+        Err(Error::NotFound(_)) if filename == "input.txt" => {
+            let fetcher = Fetcher::from_env(UreqClient::new());
+            if fetch || fetcher.is_cached(entry.year, entry.day) {
+                fetcher.input(entry.year, entry.day)
+            } else {
+                res
+            }
+        }
+        res => res,
+    }
+}
+
+fn parse_part(s: &str) -> Result<Part, String> {
+    s.parse::<u8>()
+        .ok()
+        .and_then(|part| Part::try_from(part).ok())
+        .ok_or_else(|| format!("{} is not a part, expected 1 or 2", s))
+}
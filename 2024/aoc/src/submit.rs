@@ -0,0 +1,58 @@
+use crate::http::UreqClient;
+use std::process::ExitCode;
+use utils::{
+    fetch::Fetcher,
+    solution::{Answer, Entry, Part},
+    submit::{Outcome, Verdict},
+};
+
+/// Submits the answer of a part, computing it from the input if none is
+/// given. Only exits successfully if the answer is correct.
+pub fn submit(entry: &Entry, part: Part, answer: Option<String>) -> ExitCode {
+    let answer = match answer {
+        Some(answer) => Answer::Text(answer),
+        None => match crate::read_input(entry, "input.txt", true) {
+            Ok(input) => entry.solve(&input, &[part]).remove(0),
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    if !answer.is_solved() {
+        eprintln!("Part {} of day {} is not solved yet!", part, entry.day);
+        return ExitCode::FAILURE;
+    }
+    println!("day {} part {}: {}", entry.day, part, answer);
+
+    let fetcher = Fetcher::from_env(UreqClient::new());
+    match fetcher.submit(entry.year, entry.day, part, &answer) {
+        Ok(Outcome::Submitted(verdict)) => {
+            println!("{}", verdict);
+            exit_code(&verdict)
+        }
+        Ok(Outcome::Known(verdict)) => {
+            println!("{} (known from earlier guesses, not submitted)", verdict);
+            exit_code(&verdict)
+        }
+        Ok(Outcome::Waiting(wait)) => {
+            eprintln!(
+                "The site asked to wait another {}s before answering again, not submitted",
+                wait.as_secs()
+            );
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn exit_code(verdict: &Verdict) -> ExitCode {
+    if *verdict == Verdict::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
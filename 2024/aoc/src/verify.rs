@@ -0,0 +1,156 @@
+use crate::answers::Answers;
+use crate::isolate;
+use std::fmt;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Instant;
+use utils::solution::{Answer, Entry, Part};
+
+/// Result of checking a single part against the manifest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Status {
+    Pass,
+    Fail { expected: Answer, got: Answer },
+    Missing(&'static str),
+    Panicked(String),
+}
+
+impl Status {
+    fn check(expected: Option<&Answer>, got: Answer) -> Self {
+        match expected {
+            _ if !got.is_solved() => Status::Missing("unsolved"),
+            None => Status::Missing("no answer"),
+            // Compare the text, so that e.g. `1` matches `"1"` in the manifest.
+            Some(expected) if expected.to_string() == got.to_string() => Status::Pass,
+            Some(expected) => Status::Fail {
+                expected: expected.clone(),
+                got,
+            },
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { .. } => write!(f, "FAIL"),
+            Status::Missing(reason) => write!(f, "missing ({})", reason),
+            Status::Panicked(_) => write!(f, "PANIC"),
+        }
+    }
+}
+
+/// Runs the solutions against their inputs and prints a table of the parts
+/// that pass, fail or are missing an input, answer or solution.
+pub fn verify(entries: Vec<&Entry>, answers: Option<PathBuf>) -> ExitCode {
+    let Some(first) = entries.first() else {
+        eprintln!("No solutions found!");
+        return ExitCode::FAILURE;
+    };
+    let path = answers.unwrap_or_else(|| Answers::path(first));
+    let answers = match Answers::load(&path) {
+        Ok(answers) => answers,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut failures = Vec::new();
+    let mut counts = [0; 3];
+    println!(
+        "{:>3}  {:<20}  {:<20}  {:>10}",
+        "day", "part 1", "part 2", "time"
+    );
+
+    for entry in entries {
+        let start = Instant::now();
+        let statuses: Vec<Status> = match crate::read_input(entry, "input.txt", false) {
+            Ok(input) => match isolate::run(|| entry.solve(&input, &Part::ALL)) {
+                Ok(got) => Part::ALL
+                    .iter()
+                    .zip(got)
+                    .map(|(&part, got)| Status::check(answers.get(entry.day, part), got))
+                    .collect(),
+                Err(message) => vec![Status::Panicked(message); 2],
+            },
+            Err(_) => vec![Status::Missing("no input"); 2],
+        };
+        let elapsed = start.elapsed();
+
+        println!(
+            "{:>3}  {:<20}  {:<20}  {:>10.2?}",
+            entry.day,
+            statuses[0].to_string(),
+            statuses[1].to_string(),
+            elapsed
+        );
+        for (part, status) in Part::ALL.iter().zip(statuses) {
+            match &status {
+                Status::Pass => counts[0] += 1,
+                Status::Fail { expected, got } => {
+                    counts[1] += 1;
+                    failures.push(format!(
+                        "day {} part {}: expected {}, got {}",
+                        entry.day, part, expected, got
+                    ));
+                }
+                Status::Panicked(message) => {
+                    counts[1] += 1;
+                    failures.push(format!(
+                        "day {} part {}: panicked: {}",
+                        entry.day, part, message
+                    ));
+                }
+                Status::Missing(_) => counts[2] += 1,
+            }
+        }
+    }
+
+    for failure in &failures {
+        println!("{}", failure);
+    }
+    println!(
+        "{} passed, {} failed, {} missing",
+        counts[0], counts[1], counts[2]
+    );
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let expected = Answer::Int(11);
+        assert_eq!(
+            Status::check(Some(&expected), Answer::Int(11)),
+            Status::Pass
+        );
+        assert_eq!(
+            Status::check(Some(&Answer::Text("11".to_string())), Answer::Int(11)),
+            Status::Pass
+        );
+        assert_eq!(
+            Status::check(Some(&expected), Answer::Int(12)),
+            Status::Fail {
+                expected: Answer::Int(11),
+                got: Answer::Int(12)
+            }
+        );
+        assert_eq!(
+            Status::check(None, Answer::Int(12)),
+            Status::Missing("no answer")
+        );
+        assert_eq!(
+            Status::check(Some(&expected), Answer::Unsolved),
+            Status::Missing("unsolved")
+        );
+    }
+}
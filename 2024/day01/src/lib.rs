@@ -0,0 +1,105 @@
+use utils::solution::{Answer, Solution};
+
+/*
+    Historian Hysteria - Day 1
+    Part 1: Given two lists of numbers, pair smallest-to-smallest and sum the absolute differences between pairs.
+    Part 2: For each number in left list, multiply it by its frequency in right list and sum all products.
+*/
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 1;
+
+    type Parsed<'a> = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        create_lists(input)
+    }
+
+    fn part1(lists: &Self::Parsed<'_>) -> Answer {
+        exercise1(lists).into()
+    }
+
+    fn part2(lists: &Self::Parsed<'_>) -> Answer {
+        exercise2(lists).into()
+    }
+}
+
+utils::register!(Day01);
+
+pub fn exercise1((list1, list2): &(Vec<usize>, Vec<usize>)) -> usize {
+    let mut list1 = list1.clone();
+    let mut list2 = list2.clone();
+    list1.sort();
+    list2.sort();
+
+    let mut res = 0;
+    for (a, b) in list1.iter().zip(list2.iter()) {
+        res += a.abs_diff(*b);
+    }
+    res
+}
+
+pub fn exercise2((list1, list2): &(Vec<usize>, Vec<usize>)) -> usize {
+    let mut res = 0;
+    for a in list1.iter() {
+        res += a * list2.iter().filter(|b| *b == a).count();
+    }
+    res
+}
+
+fn create_lists(input: &str) -> (Vec<usize>, Vec<usize>) {
+    let mut list1: Vec<usize> = Vec::new();
+    let mut list2: Vec<usize> = Vec::new();
+
+    for line in input.lines() {
+        let mut parts = line.split_ascii_whitespace();
+        list1.push(parts.next().unwrap().parse().unwrap());
+        list2.push(parts.next().unwrap().parse().unwrap());
+    }
+    (list1, list2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day01::parse(&input));
+            assert_eq!(res, 11);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day01::parse(&input));
+            assert_eq!(res, 2756096);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day01::parse(&input));
+            assert_eq!(res, 31);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day01::parse(&input));
+            assert_eq!(res, 23117829);
+        }
+    }
+}
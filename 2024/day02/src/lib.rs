@@ -0,0 +1,123 @@
+use utils::solution::{Answer, Solution};
+
+/*
+    Red-Nosed Reports - Day 2
+    Part 1: Count sequences where numbers are strictly increasing/decreasing by 1-3.
+    Part 2: Same as part 1, but allow removing one number to make sequence valid.
+*/
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_reports(input)
+    }
+
+    fn part1(reports: &Self::Parsed<'_>) -> Answer {
+        exercise1(reports).into()
+    }
+
+    fn part2(reports: &Self::Parsed<'_>) -> Answer {
+        exercise2(reports).into()
+    }
+}
+
+utils::register!(Day02);
+
+pub fn exercise1(reports: &[Vec<i32>]) -> usize {
+    let mut res: usize = 0;
+    for numbers in reports {
+        if is_close(numbers) {
+            res += 1;
+        }
+    }
+    res
+}
+
+pub fn exercise2(reports: &[Vec<i32>]) -> usize {
+    let mut res: usize = 0;
+    for numbers in reports {
+        for skip in 0..numbers.len() {
+            let one_less: Vec<i32> = numbers
+                .iter()
+                .enumerate()
+                .filter_map(|(i, &n)| if i != skip { Some(n) } else { None })
+                .collect();
+            if is_close(&one_less) {
+                res += 1;
+                break;
+            }
+        }
+    }
+    res
+}
+
+fn is_close(numbers: &[i32]) -> bool {
+    for window in numbers.windows(2) {
+        if (window[0] - window[1] < 0) != (numbers[0] - numbers[1] < 0)
+            || window[0].abs_diff(window[1]) < 1
+            || window[0].abs_diff(window[1]) > 3
+        {
+            return false;
+        }
+    }
+    true
+}
+
+fn parse_reports(input: &str) -> Vec<Vec<i32>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_ascii_whitespace()
+                .map(|n| n.parse::<i32>().unwrap())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day02::parse(&input));
+            assert_eq!(res, 2);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day02::parse(&input));
+            assert_eq!(res, 526);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day02::parse(&input));
+            assert_eq!(res, 4);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day02::parse(&input));
+            assert_eq!(res, 566);
+        }
+    }
+}
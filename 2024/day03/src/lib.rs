@@ -0,0 +1,122 @@
+use utils::{
+    parse::combinator::{delimited, find_all, integer, separated_pair, tag, Parser},
+    solution::{Answer, Solution},
+};
+
+/*
+    Mull It Over - Day 3
+    Part 1: Find valid mul(X,Y) instructions in corrupted text and sum their products.
+    Part 2: Same but respect do()/don't() instructions that enable/disable multiplication operations.
+*/
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        find_all(instruction(), input).collect()
+    }
+
+    fn part1(instructions: &Self::Parsed<'_>) -> Answer {
+        exercise1(instructions).into()
+    }
+
+    fn part2(instructions: &Self::Parsed<'_>) -> Answer {
+        exercise2(instructions).into()
+    }
+}
+
+utils::register!(Day03);
+
+pub fn exercise1(instructions: &[Instruction]) -> u64 {
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(n1, n2) => n1 * n2,
+            Instruction::Do | Instruction::Dont => 0,
+        })
+        .sum()
+}
+
+pub fn exercise2(instructions: &[Instruction]) -> u64 {
+    let mut res: u64 = 0;
+    let mut is_do: bool = true;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => is_do = true,
+            Instruction::Dont => is_do = false,
+            Instruction::Mul(n1, n2) if is_do => res += n1 * n2,
+            Instruction::Mul(..) => (),
+        }
+    }
+    res
+}
+
+#[derive(Clone)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+fn instruction<'a>() -> impl Parser<'a, Instruction> {
+    mul()
+        .map(|(n1, n2)| Instruction::Mul(n1, n2))
+        .or(tag("do()").value(Instruction::Do))
+        .or(tag("don't()").value(Instruction::Dont))
+}
+
+fn mul<'a>() -> impl Parser<'a, (u64, u64)> {
+    delimited(
+        tag("mul("),
+        separated_pair(integer(), tag(","), integer()),
+        tag(")"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example1.txt");
+            let res = exercise1(&Day03::parse(&input));
+            assert_eq!(res, 161);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day03::parse(&input));
+            assert_eq!(res, 157621318);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example2.txt");
+            let res = exercise2(&Day03::parse(&input));
+            assert_eq!(res, 48);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day03::parse(&input));
+            assert_eq!(res, 79845780);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use utils::{
+    grid::{Direction8, Grid, Position},
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Ceres Search - Day 4
+    Part 1: Count occurrences of 'XMAS' in a grid (horizontal, vertical, diagonal, forwards/backwards).
+    Part 2: Count occurrences of X-shaped 'MAS' patterns (two 'MAS' strings forming an X shape).
+*/
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 4;
+
+    type Parsed<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Grid::parse(input).or_panic()
+    }
+
+    fn part1(grid: &Self::Parsed<'_>) -> Answer {
+        exercise1(grid).into()
+    }
+
+    fn part2(grid: &Self::Parsed<'_>) -> Answer {
+        exercise2(grid).into()
+    }
+}
+
+utils::register!(Day04);
+
+pub fn exercise1(grid: &Grid<char>) -> usize {
+    grid.find_all(&'X')
+        .map(|pos| {
+            Direction8::iter()
+                .filter(|&dir| reads(grid, pos, dir, "XMAS"))
+                .count()
+        })
+        .sum()
+}
+
+pub fn exercise2(grid: &Grid<char>) -> usize {
+    grid.find_all(&'A')
+        .filter(|&pos| is_cross_mas(grid, pos))
+        .count()
+}
+
+/// Checks if both diagonals through `pos` read "MAS" in either direction.
+fn is_cross_mas(grid: &Grid<char>, pos: Position) -> bool {
+    [Direction8::UpLeft, Direction8::UpRight]
+        .into_iter()
+        .all(|dir| {
+            pos.step(dir).is_some_and(|start| {
+                let dir = dir.opposite();
+                reads(grid, start, dir, "MAS") || reads(grid, start, dir, "SAM")
+            })
+        })
+}
+
+/// Checks if `word` can be read starting at `pos` in the given direction.
+fn reads(grid: &Grid<char>, pos: Position, dir: Direction8, word: &str) -> bool {
+    let mut pos = Some(pos);
+    word.chars().all(|c| {
+        let matches = pos.and_then(|p| grid.get(&p)) == Some(&c);
+        pos = pos.and_then(|p| p.step(dir));
+        matches
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day04::parse(&input));
+            assert_eq!(res, 18);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day04::parse(&input));
+            assert_eq!(res, 2483);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day04::parse(&input));
+            assert_eq!(res, 9);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day04::parse(&input));
+            assert_eq!(res, 1925);
+        }
+    }
+}
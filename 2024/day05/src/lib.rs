@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+use utils::{
+    parse,
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Print Queue - Day 5
+    Part 1: Given a list of page ordering rules (X|Y means X must come before Y) and sequences of page numbers,
+            find correctly ordered sequences and sum their middle numbers.
+    Part 2: Take incorrectly ordered sequences, reorder them according to rules, and sum their middle numbers.
+*/
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = (Vec<(u32, u32)>, Vec<Vec<u32>>);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part1(manual: &Self::Parsed<'_>) -> Answer {
+        exercise1(manual).into()
+    }
+
+    fn part2(manual: &Self::Parsed<'_>) -> Answer {
+        exercise2(manual).into()
+    }
+}
+
+utils::register!(Day05);
+
+pub fn exercise1((rules, updates): &(Vec<(u32, u32)>, Vec<Vec<u32>>)) -> usize {
+    updates
+        .iter()
+        .filter(|update| {
+            update
+                .windows(2)
+                .all(|window| is_correct_pair(window[0], window[1], rules))
+        })
+        .map(|update| update[update.len() / 2] as usize)
+        .sum()
+}
+
+pub fn exercise2((rules, updates): &(Vec<(u32, u32)>, Vec<Vec<u32>>)) -> usize {
+    let mut bad_updates: Vec<Vec<u32>> = updates
+        .iter()
+        .filter(|update| {
+            update
+                .windows(2)
+                .any(|window| !is_correct_pair(window[0], window[1], rules))
+        })
+        .cloned()
+        .collect();
+
+    bad_updates
+        .iter_mut()
+        .for_each(|update| update.sort_by(|&a, &b| cmp_pair(a, b, rules)));
+    bad_updates
+        .iter()
+        .map(|update| update[update.len() / 2] as usize)
+        .sum()
+}
+
+fn is_correct_pair(n1: u32, n2: u32, rules: &[(u32, u32)]) -> bool {
+    rules.iter().any(|&rule| rule.0 == n1 && rule.1 == n2)
+}
+
+fn cmp_pair(n1: u32, n2: u32, rules: &[(u32, u32)]) -> Ordering {
+    if is_correct_pair(n1, n2, rules) {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+fn parse_input(input: &str) -> (Vec<(u32, u32)>, Vec<Vec<u32>>) {
+    parse::parse_sections(
+        input,
+        (
+            |rules: &str| rules.lines().map(|line| parse!("{}|{}", line)).collect(),
+            |updates: &str| {
+                updates
+                    .lines()
+                    .map(|line| parse::numbers(line).collect())
+                    .collect()
+            },
+        ),
+    )
+    .or_panic()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day05::parse(&input));
+            assert_eq!(res, 143);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day05::parse(&input));
+            assert_eq!(res, 5651);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day05::parse(&input));
+            assert_eq!(res, 123);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day05::parse(&input));
+            assert_eq!(res, 4743);
+        }
+    }
+}
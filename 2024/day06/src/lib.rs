@@ -0,0 +1,339 @@
+use std::ops::Not;
+use utils::{
+    grid::Direction4,
+    solution::{Answer, Solution},
+};
+
+/*
+    Guard Gallivant - Day 6
+    Part 1: Track path of guard following simple movement rules (turn right if blocked, else move forward)
+            and count distinct positions visited before leaving map.
+    Part 2: Find number of possible positions where placing an obstacle would trap guard in infinite loop.
+*/
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 6;
+
+    type Parsed<'a> = Guard;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Guard::new(input)
+    }
+
+    fn part1(guard: &Self::Parsed<'_>) -> Answer {
+        exercise1(guard).into()
+    }
+
+    fn part2(guard: &Self::Parsed<'_>) -> Answer {
+        exercise2(guard).into()
+    }
+}
+
+utils::register!(Day06);
+
+pub fn exercise1(guard: &Guard) -> usize {
+    let mut guard = guard.clone();
+
+    loop {
+        if !guard.move_forward() {
+            break;
+        }
+    }
+
+    guard.map.count_visited()
+}
+
+pub fn exercise2(guard: &Guard) -> usize {
+    let mut res: usize = 0;
+    let mut main_guard = guard.clone();
+
+    loop {
+        let mut trial_guard = main_guard.clone();
+        if !main_guard.move_forward() {
+            break;
+        }
+        if let Some(cell) = trial_guard.map.at(main_guard.row, main_guard.col) {
+            if cell.indicator == Indicator::Visited {
+                continue;
+            }
+        }
+        trial_guard
+            .map
+            .put(main_guard.row, main_guard.col, Indicator::Obstacle);
+        loop {
+            match trial_guard.move_forward() {
+                MoveResult::Success => (),
+                MoveResult::InfiniteLoop => {
+                    res += 1;
+                    break;
+                }
+                MoveResult::OutOfBounds => break,
+            }
+        }
+    }
+    res
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Indicator {
+    Start,
+    Obstacle,
+    Visited,
+    NotVisited,
+}
+
+impl From<char> for Indicator {
+    fn from(c: char) -> Self {
+        match c {
+            '^' => Indicator::Start,
+            '#' => Indicator::Obstacle,
+            'X' => Indicator::Visited,
+            '.' => Indicator::NotVisited,
+            _ => panic!("Invalid character in map found!"),
+        }
+    }
+}
+
+impl std::fmt::Display for Indicator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Indicator::Start => '^',
+            Indicator::Obstacle => '#',
+            Indicator::Visited => 'X',
+            Indicator::NotVisited => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Clone)]
+struct Cell {
+    indicator: Indicator,
+    row: i64,
+    col: i64,
+    visited: Vec<Direction4>,
+}
+
+impl Cell {
+    fn new(c: char, row: i64, col: i64) -> Self {
+        Cell {
+            indicator: Indicator::from(c),
+            row,
+            col,
+            visited: Vec::new(),
+        }
+    }
+
+    fn visit(&mut self, direction: Direction4) -> Result<(), &Vec<Direction4>> {
+        self.visited.push(direction);
+        if self.indicator == Indicator::Obstacle {
+            Err(&self.visited)
+        } else {
+            self.indicator = Indicator::Visited;
+            Ok(())
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Map {
+    grid: Vec<Vec<Cell>>,
+    height: i64,
+    width: i64,
+}
+
+impl Map {
+    fn new(input: &str) -> Self {
+        let grid: Vec<Vec<Cell>> = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| Cell::new(c, row as i64, col as i64))
+                    .collect()
+            })
+            .collect();
+        let height: i64 = grid.len() as i64;
+        let width: i64 = grid[0].len() as i64;
+        Map {
+            grid,
+            height,
+            width,
+        }
+    }
+
+    fn is_in(&self, row: i64, col: i64) -> bool {
+        (0..self.height).contains(&row) && (0..self.width).contains(&col)
+    }
+
+    fn at(&mut self, row: i64, col: i64) -> Option<&mut Cell> {
+        if self.is_in(row, col) {
+            Some(&mut self.grid[row as usize][col as usize])
+        } else {
+            None
+        }
+    }
+
+    fn put(&mut self, row: i64, col: i64, indicator: Indicator) -> bool {
+        if self.is_in(row, col) {
+            self.grid[row as usize][col as usize].indicator = indicator;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn count_visited(&self) -> usize {
+        self.grid
+            .iter()
+            .flatten()
+            .filter(|&cell| cell.indicator == Indicator::Visited)
+            .count()
+    }
+}
+
+impl std::fmt::Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let map = self
+            .grid
+            .iter()
+            .map(|line| {
+                line.iter()
+                    .map(|cell| cell.indicator.to_string())
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        write!(f, "{}", map)
+    }
+}
+
+enum MoveResult {
+    Success,
+    OutOfBounds,
+    InfiniteLoop,
+}
+
+impl Not for MoveResult {
+    type Output = bool;
+
+    fn not(self) -> Self::Output {
+        !matches!(self, MoveResult::Success)
+    }
+}
+
+#[derive(Clone)]
+pub struct Guard {
+    map: Map,
+    row: i64,
+    col: i64,
+    direction: Direction4,
+}
+
+impl Guard {
+    fn new(input: &str) -> Self {
+        let mut map = Map::new(input);
+        let mut row: i64 = -1;
+        let mut col: i64 = -1;
+        let direction = Direction4::Up;
+
+        if let Some(cell) = map
+            .grid
+            .iter_mut()
+            .flatten()
+            .find(|cell| cell.indicator == Indicator::Start)
+        {
+            row = cell.row;
+            col = cell.col;
+            cell.indicator = Indicator::Visited;
+        }
+        Guard {
+            map,
+            row,
+            col,
+            direction,
+        }
+    }
+
+    fn move_forward(&mut self) -> MoveResult {
+        self.try_move(self.direction)
+    }
+
+    /// Moves one step into `direction`, turning right at obstacles.
+    fn try_move(&mut self, direction: Direction4) -> MoveResult {
+        let (row_delta, col_delta) = direction.offset();
+        let new_row = self.row + row_delta as i64;
+        let new_col = self.col + col_delta as i64;
+        self.direction = direction;
+
+        if let Some(cell) = self.map.at(new_row, new_col) {
+            match cell.visit(self.direction) {
+                Ok(_) => {
+                    self.row = new_row;
+                    self.col = new_col;
+                    MoveResult::Success
+                }
+                Err(visited) => {
+                    if visited
+                        .iter()
+                        .filter(|&&direction| direction == self.direction)
+                        .count()
+                        <= 1
+                    {
+                        self.try_move(direction.clockwise())
+                    } else {
+                        MoveResult::InfiniteLoop
+                    }
+                }
+            }
+        } else {
+            MoveResult::OutOfBounds
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day06::parse(&input));
+            assert_eq!(res, 41);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day06::parse(&input));
+            assert_eq!(res, 4826);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day06::parse(&input));
+            assert_eq!(res, 6);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day06::parse(&input));
+            assert_eq!(res, 1721);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use itertools::Itertools;
+use std::{collections::HashMap, fmt};
+use utils::{
+    grid::{Grid, Vec2},
+    solution::{Answer, Solution},
+};
+
+/*
+    Resonant Collinearity - Day 8
+    Part 1: Count unique antinode locations where two same-frequency antennas are aligned with one being twice as far as the other.
+    Part 2: Count unique antinode locations where any two or more same-frequency antennas are aligned, regardless of distance.
+*/
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 8;
+
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> Answer {
+        exercise1(map).into()
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> Answer {
+        exercise2(map).into()
+    }
+}
+
+utils::register!(Day08);
+
+pub fn exercise1(map: &Map) -> usize {
+    let mut antinodes = Vec::new();
+
+    for antennas in map.antennas.values() {
+        for combination in antennas.iter().combinations(2) {
+            antinodes.extend(get_antinodes1(*combination[0], *combination[1]));
+        }
+    }
+    count_antinodes(map, &antinodes)
+}
+
+pub fn exercise2(map: &Map) -> usize {
+    let mut antinodes = Vec::new();
+
+    for antennas in map.antennas.values() {
+        for combination in antennas.iter().combinations(2) {
+            antinodes.extend(get_antinodes2(*combination[0], *combination[1], map));
+        }
+    }
+    count_antinodes(map, &antinodes)
+}
+
+/// Counts the distinct antinodes within the map.
+fn count_antinodes(map: &Map, antinodes: &[Vec2<i32>]) -> usize {
+    antinodes
+        .iter()
+        .filter(|&&antinode| map.is_in(antinode))
+        .unique()
+        .count()
+}
+
+fn get_antinodes1(antenna1: Vec2<i32>, antenna2: Vec2<i32>) -> Vec<Vec2<i32>> {
+    let diff = antenna2 - antenna1;
+    vec![antenna1 - diff, antenna2 + diff]
+}
+
+fn get_antinodes2(antenna1: Vec2<i32>, antenna2: Vec2<i32>, map: &Map) -> Vec<Vec2<i32>> {
+    let diff = antenna2 - antenna1;
+    let mut antinodes = get_points_in_line(antenna1, diff, map);
+    antinodes.extend(get_points_in_line(antenna1, -diff, map));
+    antinodes
+}
+
+fn get_points_in_line(start: Vec2<i32>, diff: Vec2<i32>, map: &Map) -> Vec<Vec2<i32>> {
+    let mut points = Vec::new();
+    let mut point = start;
+    while map.is_in(point) {
+        points.push(point);
+        point += diff;
+    }
+    points
+}
+
+pub struct Map {
+    grid: Grid<char>,
+    antennas: HashMap<char, Vec<Vec2<i32>>>,
+}
+
+impl Map {
+    fn new(input: &str) -> Self {
+        let grid = Grid::from(input);
+        let mut antennas: HashMap<char, Vec<Vec2<i32>>> = HashMap::new();
+
+        for (pos, &ch) in grid.iter().filter(|(_, &ch)| ch != '.') {
+            antennas
+                .entry(ch)
+                .or_default()
+                .push(Vec2::from_position(pos).unwrap());
+        }
+        Map { grid, antennas }
+    }
+
+    fn is_in(&self, point: Vec2<i32>) -> bool {
+        point
+            .to_position()
+            .is_some_and(|pos| self.grid.contains(&pos))
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example1_1() {
+            let input = input::read_file("example1_1.txt");
+            let res = exercise1(&Day08::parse(&input));
+            assert_eq!(res, 2);
+        }
+
+        #[test]
+        fn example1_2() {
+            let input = input::read_file("example1_2.txt");
+            let res = exercise1(&Day08::parse(&input));
+            assert_eq!(res, 4);
+        }
+
+        #[test]
+        fn example1_3() {
+            let input = input::read_file("example1_3.txt");
+            let res = exercise1(&Day08::parse(&input));
+            assert_eq!(res, 4);
+        }
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day08::parse(&input));
+            assert_eq!(res, 14);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day08::parse(&input));
+            assert_eq!(res, 311);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example2_1() {
+            let input = input::read_file("example2_1.txt");
+            let res = exercise2(&Day08::parse(&input));
+            assert_eq!(res, 9);
+        }
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day08::parse(&input));
+            assert_eq!(res, 34);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day08::parse(&input));
+            assert_eq!(res, 1115);
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use itertools::Itertools;
+use utils::{
+    grid::{Direction4, Grid, Position},
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Hoof It - Day 10
+    Part 1: Find trailheads (height 0) and count how many height-9 positions each can reach via paths that increase by 1. Sum all scores.
+    Part 2: For each trailhead, count number of distinct possible paths to any height-9 position. Sum all these counts.
+*/
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 10;
+
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> Answer {
+        exercise1(map).into()
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> Answer {
+        exercise2(map).into()
+    }
+}
+
+utils::register!(Day10);
+
+pub fn exercise1(map: &Map) -> usize {
+    map.trails().iter().unique().count()
+}
+
+pub fn exercise2(map: &Map) -> usize {
+    map.trails().len()
+}
+
+fn hike(map: &Map, start: &Cell, from: &Cell, trails: &mut Vec<(Position, Position)>) {
+    for direction in Direction4::iter() {
+        if let Some(to) = map.try_move(from, direction) {
+            if to.value == 9 {
+                trails.push((start.pos, to.pos));
+            } else {
+                hike(map, start, to, trails);
+            }
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Cell {
+    pos: Position,
+    value: u8,
+}
+
+impl Cell {
+    fn new(row: usize, col: usize, value: u8) -> Self {
+        Cell {
+            pos: Position::new(row, col),
+            value,
+        }
+    }
+}
+
+pub struct Map {
+    grid: Grid<Cell>,
+    starts: Vec<Position>,
+}
+
+impl Map {
+    fn new(input: &str) -> Self {
+        let grid = Grid::parse_with(input, |pos, ch| {
+            let digit = ch.to_digit(10).unwrap_or(u8::MAX as u32);
+            Some(Cell::new(pos.row, pos.col, digit as u8))
+        })
+        .or_panic();
+        Map {
+            starts: grid
+                .iter()
+                .filter(|(_, cell)| cell.value == 0)
+                .map(|(pos, _)| pos)
+                .collect(),
+            grid,
+        }
+    }
+
+    /// Every hiking trail as a (trailhead, summit) pair, one entry per distinct path.
+    fn trails(&self) -> Vec<(Position, Position)> {
+        let mut trails = Vec::new();
+        for start in &self.starts {
+            let start_cell = self.at(start).unwrap();
+            hike(self, start_cell, start_cell, &mut trails);
+        }
+        trails
+    }
+
+    fn try_move(&self, from: &Cell, direction: Direction4) -> Option<&Cell> {
+        let to = self.at(&from.pos.step(direction)?)?;
+        if to.value == from.value + 1 {
+            Some(to)
+        } else {
+            None
+        }
+    }
+
+    fn at(&self, pos: &Position) -> Option<&Cell> {
+        self.grid.get(pos)
+    }
+}
+
+impl std::fmt::Display for Map {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid.rows() {
+            for cell in row {
+                write!(f, "{}", cell.value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example1_1() {
+            let input = input::read_file("example1_1.txt");
+            let res = exercise1(&Day10::parse(&input));
+            assert_eq!(res, 1);
+        }
+
+        #[test]
+        fn example1_2() {
+            let input = input::read_file("example1_2.txt");
+            let res = exercise1(&Day10::parse(&input));
+            assert_eq!(res, 2);
+        }
+
+        #[test]
+        fn example1_3() {
+            let input = input::read_file("example1_3.txt");
+            let res = exercise1(&Day10::parse(&input));
+            assert_eq!(res, 4);
+        }
+
+        #[test]
+        fn example1_4() {
+            let input = input::read_file("example1_4.txt");
+            let res = exercise1(&Day10::parse(&input));
+            assert_eq!(res, 3);
+        }
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day10::parse(&input));
+            assert_eq!(res, 36);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day10::parse(&input));
+            assert_eq!(res, 754);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example2_1() {
+            let input = input::read_file("example2_1.txt");
+            let res = exercise2(&Day10::parse(&input));
+            assert_eq!(res, 3);
+        }
+
+        #[test]
+        fn example2_2() {
+            let input = input::read_file("example2_2.txt");
+            let res = exercise2(&Day10::parse(&input));
+            assert_eq!(res, 13);
+        }
+
+        #[test]
+        fn example2_3() {
+            let input = input::read_file("example2_3.txt");
+            let res = exercise2(&Day10::parse(&input));
+            assert_eq!(res, 227);
+        }
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day10::parse(&input));
+            assert_eq!(res, 81);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day10::parse(&input));
+            assert_eq!(res, 1609);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use utils::{
+    memo::Memo,
+    solution::{Answer, Solution},
+};
+
+/*
+    Plutonian Pebbles - Day 11
+    Part 1: Transform stones with numbers according to rules (if 0 -> 1, if even digits -> split in half, else -> multiply by 2024)
+            for 25 iterations and count resulting amount of stones.
+    Part 2: Same as part 1, but for 75 iterations.
+*/
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 11;
+
+    type Parsed<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_stones(input)
+    }
+
+    fn part1(stones: &Self::Parsed<'_>) -> Answer {
+        exercise1(stones).into()
+    }
+
+    fn part2(stones: &Self::Parsed<'_>) -> Answer {
+        exercise2(stones).into()
+    }
+}
+
+utils::register!(Day11);
+
+pub fn exercise1(stones: &[usize]) -> usize {
+    let mut res: usize = 0;
+
+    for &stone in stones {
+        res += split_stone_n_times(stone, 25);
+    }
+    res
+}
+
+pub fn exercise2(stones: &[usize]) -> usize {
+    let mut memo = Memo::new();
+    stones
+        .iter()
+        .map(|&stone| split_stone_n_times_cached(stone, 75, &mut memo))
+        .sum()
+}
+
+fn split_stone_n_times(mut stone: usize, mut n: usize) -> usize {
+    let mut res: usize = 1;
+    while n > 0 {
+        if stone == 0 {
+            stone = 1;
+        } else if let Some((left, right)) = split_if_even_digits(stone) {
+            res = split_stone_n_times(left, n - 1) + split_stone_n_times(right, n - 1);
+            break;
+        } else {
+            stone *= 2024;
+        }
+        n -= 1;
+    }
+    res
+}
+
+fn split_stone_n_times_cached(
+    stone: usize,
+    n: usize,
+    memo: &mut Memo<(usize, usize), usize>,
+) -> usize {
+    memo.compute((stone, n), |split, &(mut stone, mut n)| {
+        let mut res: usize = 1;
+        while n > 0 {
+            if stone == 0 {
+                stone = 1;
+            } else if let Some((left, right)) = split_if_even_digits(stone) {
+                res = split((left, n - 1)) + split((right, n - 1));
+                break;
+            } else {
+                stone *= 2024;
+            }
+            n -= 1;
+        }
+        res
+    })
+}
+
+fn split_if_even_digits(num: usize) -> Option<(usize, usize)> {
+    let string = num.to_string();
+    if string.len().is_multiple_of(2) {
+        let (left, right) = string.split_at(string.len() / 2);
+        Some((left.parse().unwrap(), right.parse().unwrap()))
+    } else {
+        None
+    }
+}
+
+fn parse_stones(input: &str) -> Vec<usize> {
+    input
+        .split_whitespace()
+        .map(|n| n.parse().unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day11::parse(&input));
+            assert_eq!(res, 55312);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day11::parse(&input));
+            assert_eq!(res, 183484);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day11::parse(&input));
+            assert_eq!(res, 218817038947400);
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use itertools::Itertools;
+use utils::{
+    grid::Vec2,
+    parse,
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Claw Contraption - Day 13
+    Part 1: Find minimum tokens needed to win prizes by pressing A (3 tokens) and B (1 token) buttons that move claw by X,Y amounts to reach prize coordinates.
+    Part 2: Same but with prize coordinates increased by 10^13 on both X and Y axes.
+*/
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 13;
+
+    type Parsed<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Self::Parsed<'_>) -> Answer {
+        exercise1(machines).into()
+    }
+
+    fn part2(machines: &Self::Parsed<'_>) -> Answer {
+        exercise2(machines).into()
+    }
+}
+
+utils::register!(Day13);
+
+const COST_A: i64 = 3;
+const COST_B: i64 = 1;
+const MAX_PRESSES: i64 = 100;
+const GREAT_DISTANCE: Vec2<i64> = Vec2::new(10000000000000, 10000000000000);
+
+/// The two buttons of a claw machine and the location of its prize.
+pub type Machine = (Button, Button, Vec2<i64>);
+
+pub fn exercise1(machines: &[Machine]) -> i64 {
+    let mut res: i64 = 0;
+    for &(button_a, button_b, prize) in machines {
+        if let Some(min_cost) = try_all_combinations(button_a, button_b, prize) {
+            res += min_cost;
+        }
+    }
+    res
+}
+
+pub fn exercise2(machines: &[Machine]) -> i64 {
+    let mut res: i64 = 0;
+    for &(mut button_a, mut button_b, prize) in machines {
+        if move_to_prize(&mut button_a, &mut button_b, prize + GREAT_DISTANCE) {
+            res += button_a.get_cost() + button_b.get_cost();
+        }
+    }
+    res
+}
+
+fn move_to_prize(button_a: &mut Button, button_b: &mut Button, prize: Vec2<i64>) -> bool {
+    let ax = button_a.movement.x;
+    let ay = button_a.movement.y;
+    let bx = button_b.movement.x;
+    let by = button_b.movement.y;
+    let px = prize.x;
+    let py = prize.y;
+
+    let a = divide_if_whole(px * by - py * bx, ax * by - ay * bx);
+    if a.is_none() {
+        return false;
+    }
+    let b = divide_if_whole(px - ax * a.unwrap(), bx);
+    if b.is_none() {
+        return false;
+    }
+    button_a.presses = a.unwrap();
+    button_b.presses = b.unwrap();
+    true
+}
+
+fn divide_if_whole(a: i64, b: i64) -> Option<i64> {
+    if a % b == 0 {
+        Some(a / b)
+    } else {
+        None
+    }
+}
+
+fn try_all_combinations(button_a: Button, button_b: Button, prize: Vec2<i64>) -> Option<i64> {
+    let mut min_cost: Option<i64> = None;
+
+    for combination in (0..=MAX_PRESSES).permutations(2) {
+        if let Some(cost) = calc_cost(
+            Button::new(
+                button_a.movement.x,
+                button_a.movement.y,
+                combination[0],
+                COST_A,
+            ),
+            Button::new(
+                button_b.movement.x,
+                button_b.movement.y,
+                combination[1],
+                COST_B,
+            ),
+            prize,
+        ) {
+            if min_cost.is_none_or(|min_cost| cost < min_cost) {
+                min_cost = Some(cost);
+            }
+        }
+    }
+    min_cost
+}
+
+fn calc_cost(button_a: Button, button_b: Button, prize: Vec2<i64>) -> Option<i64> {
+    if button_a.apply() + button_b.apply() == prize {
+        Some(button_a.get_cost() + button_b.get_cost())
+    } else {
+        None
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Button {
+    movement: Vec2<i64>,
+    presses: i64,
+    cost: i64,
+}
+
+impl Button {
+    fn new(x: i64, y: i64, presses: i64, cost: i64) -> Self {
+        Button {
+            movement: Vec2::new(x, y),
+            presses,
+            cost,
+        }
+    }
+
+    fn apply(&self) -> Vec2<i64> {
+        self.movement * self.presses
+    }
+
+    fn get_cost(&self) -> i64 {
+        self.presses * self.cost
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Machine> {
+    parse::each_section(input, |block| {
+        let mut lines = block.lines();
+        let mut next_line = |pattern| parse::scan(pattern, lines.next().unwrap_or_default());
+        let (a_x, a_y) = next_line("Button A: X+{}, Y+{}")?;
+        let (b_x, b_y) = next_line("Button B: X+{}, Y+{}")?;
+        let (prize_x, prize_y) = next_line("Prize: X={}, Y={}")?;
+        Ok((
+            Button::new(a_x, a_y, 0, COST_A),
+            Button::new(b_x, b_y, 0, COST_B),
+            Vec2::new(prize_x, prize_y),
+        ))
+    })
+    .map(|machine| machine.or_panic())
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day13::parse(&input));
+            assert_eq!(res, 480);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day13::parse(&input));
+            assert_eq!(res, 27105);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day13::parse(&input));
+            assert_eq!(res, 101726882250942);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use utils::{
+    grid::{Grid, Vec2},
+    image::{self, Image, Rgb},
+    parse,
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Restroom Redoubt - Day 14
+    Part 1: Predict positions of robots moving in straight lines that wrap around edges after 100 movements.
+            Then multiply number of robots in each quadrant.
+    Part 2: Find minimum movements needed for robots to form a Christmas tree pattern.
+*/
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 14;
+
+    type Parsed<'a> = World;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        World::new(input)
+    }
+
+    fn part1(world: &Self::Parsed<'_>) -> Answer {
+        exercise1(world).into()
+    }
+
+    fn part2(world: &Self::Parsed<'_>) -> Answer {
+        exercise2(world).into()
+    }
+}
+
+utils::register!(Day14);
+
+pub fn exercise1(world: &World) -> usize {
+    const ITERATIONS: usize = 100;
+    let mut world = world.clone();
+
+    for _ in 0..ITERATIONS {
+        world.mv_robots();
+    }
+    world.count_quadrant(Quadrant::TopLeft)
+        * world.count_quadrant(Quadrant::TopRight)
+        * world.count_quadrant(Quadrant::BottomLeft)
+        * world.count_quadrant(Quadrant::BottomRight)
+}
+
+pub fn exercise2(world: &World) -> usize {
+    let mut world = world.clone();
+    let mut i: usize = 0;
+
+    while world.any_overlap() {
+        world.mv_robots();
+        i += 1;
+    }
+    i
+}
+
+const ROBOT: Rgb = [0, 200, 0];
+
+/// Renders the world after every second, until the robots are back at their
+/// starting positions.
+pub fn frames(world: &World) -> impl Iterator<Item = String> {
+    simulate(world, World::to_string)
+}
+
+/// Like [`frames`], but draws images.
+pub fn images(world: &World) -> impl Iterator<Item = Image> {
+    simulate(world, World::image)
+}
+
+fn simulate<T>(world: &World, mut render: impl FnMut(&World) -> T) -> impl Iterator<Item = T> {
+    let mut world = world.clone();
+    let period = (world.size.x * world.size.y) as usize;
+    (0..period).map(move |_| {
+        let frame = render(&world);
+        world.mv_robots();
+        frame
+    })
+}
+
+enum Quadrant {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Quadrant {
+    fn is_in(&self, robot: &Robot, world: &World) -> bool {
+        let range_x = match self {
+            Self::TopLeft | Self::BottomLeft => 0..world.size.x / 2,
+            Self::TopRight | Self::BottomRight => world.size.x / 2 + 1..world.size.x,
+        };
+        let range_y = match self {
+            Self::TopLeft | Self::TopRight => 0..world.size.y / 2,
+            Self::BottomLeft | Self::BottomRight => world.size.y / 2 + 1..world.size.y,
+        };
+        range_x.contains(&robot.pos.x) && range_y.contains(&robot.pos.y)
+    }
+}
+
+#[derive(Clone)]
+pub struct World {
+    robots: Vec<Robot>,
+    size: Vec2<i64>,
+}
+
+impl World {
+    fn new(input: &str) -> Self {
+        let robots = parse_input(input);
+        let size = Vec2::new(
+            robots.iter().map(|robot| robot.pos.x).max().unwrap() + 1,
+            robots.iter().map(|robot| robot.pos.y).max().unwrap() + 1,
+        );
+        Self { robots, size }
+    }
+
+    fn mv_robots(&mut self) {
+        for robot in &mut self.robots {
+            robot.pos = robot.pos.wrapping_add(robot.vel, self.size);
+        }
+    }
+
+    fn image(&self) -> Image {
+        let mut robots = Grid::filled(self.size.x as usize, self.size.y as usize, false);
+        for robot in &self.robots {
+            robots[robot.pos.to_position().unwrap()] = true;
+        }
+        robots.to_image(4, |_, &robot| if robot { ROBOT } else { image::BLACK })
+    }
+
+    fn count_quadrant(&self, quadrant: Quadrant) -> usize {
+        self.robots
+            .iter()
+            .filter(|robot| quadrant.is_in(robot, self))
+            .count()
+    }
+
+    fn any_overlap(&self) -> bool {
+        let mut world: Vec<Vec<bool>> =
+            vec![vec![false; self.size.x as usize]; self.size.y as usize];
+
+        for robot in &self.robots {
+            let is_robot = &mut world[robot.pos.y as usize][robot.pos.x as usize];
+            if *is_robot {
+                return true;
+            }
+            *is_robot = true;
+        }
+        false
+    }
+}
+
+impl std::fmt::Display for World {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut world: Vec<Vec<char>> = vec![vec!['.'; self.size.x as usize]; self.size.y as usize];
+
+        for robot in &self.robots {
+            let c: &mut char = &mut world[robot.pos.y as usize][robot.pos.x as usize];
+            *c = match *c {
+                '.' => '1',
+                _ => (*c as u8 + 1) as char,
+            }
+        }
+        for row in world {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+struct Robot {
+    pos: Vec2<i64>,
+    vel: Vec2<i64>,
+}
+
+fn parse_input(input: &str) -> Vec<Robot> {
+    input
+        .lines()
+        .map(|line| {
+            let [x, y, vel_x, vel_y] = parse::n_numbers(line).or_panic();
+            Robot {
+                pos: Vec2::new(x, y),
+                vel: Vec2::new(vel_x, vel_y),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day14::parse(&input));
+            assert_eq!(res, 12);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day14::parse(&input));
+            assert_eq!(res, 230461440);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day14::parse(&input));
+            assert_eq!(res, 6668);
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::VecDeque;
+use utils::{
+    grid::{Direction4, Position},
+    parse,
+    solution::{Answer, Solution},
+    Error, OrPanic,
+};
+
+/*
+    Warehouse Woes - Day 15
+    Part 1: Track a robot (@) pushing boxes (O) around a warehouse according to movement instructions (^v<>).
+            Calculate sum of box GPS coordinates (row * 100 + col) after all moves.
+    Part 2: Same as part 1, but with double-width warehouse and boxes ([]). Robot stays same size.
+*/
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 15;
+
+    type Parsed<'a> = (&'a str, VecDeque<Direction4>);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> Answer {
+        exercise1(input).into()
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> Answer {
+        exercise2(input).into()
+    }
+}
+
+utils::register!(Day15);
+
+pub fn exercise1((warehouse, movements): &(&str, VecDeque<Direction4>)) -> usize {
+    let mut map = Map::new(warehouse, false);
+    let mut robot = Robot::new(&map, movements.clone());
+
+    while !robot.movements.is_empty() {
+        robot.mv(&mut map);
+    }
+    map.boxes().map(|object| object.gps_coordinate()).sum()
+}
+
+pub fn exercise2((warehouse, movements): &(&str, VecDeque<Direction4>)) -> usize {
+    let mut map = Map::new(warehouse, true);
+    let mut robot = Robot::new(&map, movements.clone());
+
+    while !robot.movements.is_empty() {
+        robot.mv(&mut map);
+    }
+    map.boxes().map(|object| object.gps_coordinate()).sum()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum BoxPart {
+    Left,
+    Right,
+}
+
+impl BoxPart {
+    fn other(&self, pos: Position) -> Position {
+        match self {
+            BoxPart::Left => Position::new(pos.row, pos.col + 1),
+            BoxPart::Right => Position::new(pos.row, pos.col - 1),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ObjectKind {
+    Empty,
+    Wall,
+    Box(Option<BoxPart>),
+    Robot,
+}
+
+struct Object {
+    kind: ObjectKind,
+    pos: Position,
+}
+
+impl Object {
+    fn new(c: char, row: usize, col: usize) -> Self {
+        let pos = Position::new(row, col);
+        let kind = match c {
+            '.' => ObjectKind::Empty,
+            '#' => ObjectKind::Wall,
+            'O' => ObjectKind::Box(None),
+            '@' => ObjectKind::Robot,
+            _ => panic!("Invalid character in map found!"),
+        };
+        Object { kind, pos }
+    }
+
+    fn twice(c: char, row: usize, col: usize) -> [Self; 2] {
+        let (pos1, pos2) = (Position::new(row, col), Position::new(row, col + 1));
+        let (kind1, kind2) = match c {
+            '.' => (ObjectKind::Empty, ObjectKind::Empty),
+            '#' => (ObjectKind::Wall, ObjectKind::Wall),
+            'O' => (
+                ObjectKind::Box(Some(BoxPart::Left)),
+                ObjectKind::Box(Some(BoxPart::Right)),
+            ),
+            '@' => (ObjectKind::Robot, ObjectKind::Empty),
+            _ => panic!("Invalid character in map found!"),
+        };
+        [
+            Object {
+                kind: kind1,
+                pos: pos1,
+            },
+            Object {
+                kind: kind2,
+                pos: pos2,
+            },
+        ]
+    }
+
+    fn gps_coordinate(&self) -> usize {
+        self.pos.row * 100 + self.pos.col
+    }
+}
+
+struct Robot {
+    pos: Position,
+    movements: VecDeque<Direction4>,
+}
+
+impl Robot {
+    fn new(map: &Map, movements: VecDeque<Direction4>) -> Self {
+        Self {
+            pos: map
+                .grid
+                .iter()
+                .flatten()
+                .find(|object| object.kind == ObjectKind::Robot)
+                .expect("No robot in map found!")
+                .pos,
+            movements,
+        }
+    }
+
+    fn mv(&mut self, map: &mut Map) -> bool {
+        if let Some(direction) = self.movements.pop_front() {
+            if map.mv_object(self.pos, direction) {
+                self.pos = self.pos.step(direction).unwrap();
+                return true;
+            }
+        }
+        false
+    }
+}
+
+struct Map {
+    grid: Vec<Vec<Object>>,
+}
+
+impl Map {
+    fn new(warehouse: &str, is_wide: bool) -> Self {
+        let mut grid: Vec<Vec<Object>> = Vec::new();
+
+        for (row, line) in warehouse.lines().enumerate() {
+            let mut grid_line: Vec<Object> = Vec::new();
+            for (mut col, c) in line.chars().enumerate() {
+                if is_wide {
+                    col *= 2;
+                    grid_line.extend(Object::twice(c, row, col));
+                } else {
+                    grid_line.push(Object::new(c, row, col));
+                }
+            }
+            grid.push(grid_line);
+        }
+        Self { grid }
+    }
+
+    fn can_move(&self, pos: Position, direction: Direction4) -> bool {
+        use ObjectKind::*;
+        match self.at(pos).kind {
+            Empty => true,
+            Wall => false,
+            Box(Some(part)) => {
+                self.can_move(pos.step(direction).unwrap(), direction)
+                    && (direction.is_horizontal()
+                        || self.can_move(part.other(pos).step(direction).unwrap(), direction))
+            }
+            Box(None) | Robot => self.can_move(pos.step(direction).unwrap(), direction),
+        }
+    }
+
+    fn mv_object(&mut self, pos: Position, direction: Direction4) -> bool {
+        use ObjectKind::*;
+
+        if !self.can_move(pos, direction) {
+            return false;
+        }
+        match self.at(pos).kind {
+            Empty => true,
+            Wall => false,
+            Box(Some(part)) => {
+                let new_pos = pos.step(direction).unwrap();
+                self.mv_object(new_pos, direction);
+                self.swap(pos, new_pos);
+                if direction.is_vertical() {
+                    let other_new_pos = part.other(pos).step(direction).unwrap();
+                    self.mv_object(other_new_pos, direction);
+                    self.swap(part.other(pos), other_new_pos);
+                }
+                true
+            }
+            Box(None) | Robot => {
+                let new_pos = pos.step(direction).unwrap();
+                if self.mv_object(new_pos, direction) {
+                    self.swap(pos, new_pos);
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    fn swap(&mut self, pos1: Position, pos2: Position) {
+        let tmp = self.at(pos1).kind;
+        self.at_mut(pos1).kind = self.at(pos2).kind;
+        self.at_mut(pos2).kind = tmp;
+    }
+
+    fn boxes(&self) -> impl Iterator<Item = &Object> {
+        self.grid.iter().flatten().filter(|object| {
+            matches!(
+                object.kind,
+                ObjectKind::Box(None) | ObjectKind::Box(Some(BoxPart::Left))
+            )
+        })
+    }
+
+    fn at(&self, pos: Position) -> &Object {
+        &self.grid[pos.row][pos.col]
+    }
+
+    fn at_mut(&mut self, pos: Position) -> &mut Object {
+        &mut self.grid[pos.row][pos.col]
+    }
+}
+
+fn parse_input(input: &str) -> (&str, VecDeque<Direction4>) {
+    parse::parse_sections(input, (parse_warehouse, parse_movements)).or_panic()
+}
+
+fn parse_warehouse(section: &str) -> utils::Result<&str> {
+    Ok(section)
+}
+
+fn parse_movements(section: &str) -> utils::Result<VecDeque<Direction4>> {
+    section
+        .lines()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars().enumerate().map(move |(col, c)| {
+                Direction4::try_from(c).map_err(|found| Error::InvalidChar {
+                    line: row + 1,
+                    col: col + 1,
+                    found,
+                })
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example1() {
+            let input = input::read_file("example1.txt");
+            let res = exercise1(&Day15::parse(&input));
+            assert_eq!(res, 2028);
+        }
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day15::parse(&input));
+            assert_eq!(res, 10092);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day15::parse(&input));
+            assert_eq!(res, 1568399);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day15::parse(&input));
+            assert_eq!(res, 9021);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day15::parse(&input));
+            assert_eq!(res, 1575877);
+        }
+    }
+}
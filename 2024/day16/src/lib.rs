@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+use utils::{
+    grid::{Direction4, Grid, Position},
+    image::{self, Image, Rgb},
+    search,
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Reindeer Maze - Day 16
+    Part 1: Find lowest possible score to reach the end of a maze (1 point per step, 1000 points per turn).
+    Part 2: Count how many tiles are part of any optimal (lowest-score) path through the maze.
+*/
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 16;
+
+    type Parsed<'a> = Map;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> Answer {
+        exercise1(map).into()
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> Answer {
+        exercise2(map).into()
+    }
+}
+
+utils::register!(Day16);
+
+pub fn exercise1(map: &Map) -> i64 {
+    let search = search::dijkstra(
+        State::new(map.start, Direction4::Right),
+        |state| map.moves(state),
+        |state| state.pos == map.goal,
+    );
+    search.goal_cost().expect("No path to the goal found!")
+}
+
+pub fn exercise2(map: &Map) -> usize {
+    best_path_states(map)
+        .iter()
+        .map(|state| state.pos)
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+/// Draws the maze with the tiles of all best paths highlighted.
+pub fn best_paths_image(map: &Map) -> Image {
+    let tiles: HashSet<Position> = best_path_states(map)
+        .into_iter()
+        .map(|state| state.pos)
+        .collect();
+    map.grid.to_image(4, |pos, tile| match tile {
+        _ if tiles.contains(&pos) => PATH,
+        TileKind::Wall => WALL,
+        _ => image::WHITE,
+    })
+}
+
+const PATH: Rgb = [255, 200, 0];
+const WALL: Rgb = [60, 60, 60];
+
+/// Returns the states on any of the best paths from the start to the goal.
+fn best_path_states(map: &Map) -> HashSet<State> {
+    let best_paths = search::dijkstra_all(
+        State::new(map.start, Direction4::Right),
+        |state| map.moves(state),
+        |state| state.pos == map.goal,
+    );
+    assert!(!best_paths.goals().is_empty(), "No path to the goal found!");
+    best_paths.nodes()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct State {
+    pos: Position,
+    facing: Direction4,
+}
+
+impl State {
+    fn new(pos: Position, facing: Direction4) -> Self {
+        Self { pos, facing }
+    }
+
+    fn front(&self) -> Self {
+        Self {
+            pos: self.pos.step(self.facing).unwrap(),
+            facing: self.facing,
+        }
+    }
+
+    fn right(&self) -> Self {
+        Self {
+            pos: self.pos.step(self.facing.clockwise()).unwrap(),
+            facing: self.facing.clockwise(),
+        }
+    }
+
+    fn left(&self) -> Self {
+        Self {
+            pos: self.pos.step(self.facing.counter_clockwise()).unwrap(),
+            facing: self.facing.counter_clockwise(),
+        }
+    }
+}
+
+#[derive(PartialEq, Eq)]
+enum TileKind {
+    Wall,
+    Path,
+    Start,
+    Goal,
+}
+
+impl TryFrom<char> for TileKind {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(TileKind::Wall),
+            '.' => Ok(TileKind::Path),
+            'S' => Ok(TileKind::Start),
+            'E' => Ok(TileKind::Goal),
+            _ => Err(c),
+        }
+    }
+}
+
+impl std::fmt::Display for TileKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TileKind::Wall => '#',
+                TileKind::Path => '.',
+                TileKind::Start => 'S',
+                TileKind::Goal => 'E',
+            }
+        )
+    }
+}
+
+pub struct Map {
+    grid: Grid<TileKind>,
+    start: Position,
+    goal: Position,
+}
+
+impl Map {
+    fn new(input: &str) -> Self {
+        let (grid, markers) = Grid::parse_markers(input, "SE").or_panic();
+        Self {
+            grid,
+            start: markers.get('S').expect("No start tile ('S') found!"),
+            goal: markers.get('E').expect("No goal tile ('E') found!"),
+        }
+    }
+
+    /// Returns the states reachable in one move and what the move costs.
+    fn moves(&self, state: &State) -> impl Iterator<Item = (State, i64)> + '_ {
+        [
+            (state.front(), 1),
+            (state.right(), 1001),
+            (state.left(), 1001),
+        ]
+        .into_iter()
+        .filter(|(next, _)| self.grid[next.pos] != TileKind::Wall)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example1() {
+            let input = input::read_file("example1.txt");
+            let res = exercise1(&Day16::parse(&input));
+            assert_eq!(res, 7036);
+        }
+
+        #[test]
+        fn example2() {
+            let input = input::read_file("example2.txt");
+            let res = exercise1(&Day16::parse(&input));
+            assert_eq!(res, 11048);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day16::parse(&input));
+            assert_eq!(res, 89460);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example1() {
+            let input = input::read_file("example1.txt");
+            let res = exercise2(&Day16::parse(&input));
+            assert_eq!(res, 45);
+        }
+
+        #[test]
+        fn example2() {
+            let input = input::read_file("example2.txt");
+            let res = exercise2(&Day16::parse(&input));
+            assert_eq!(res, 64);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day16::parse(&input));
+            assert_eq!(res, 504);
+        }
+    }
+}
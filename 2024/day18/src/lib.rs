@@ -0,0 +1,191 @@
+use std::{cmp::min, collections::HashSet};
+use utils::{
+    grid::{Grid, Position},
+    image::{self, Image, Rgb},
+    search::{self, Strategy},
+    solution::{Answer, Solution},
+};
+
+/*
+    RAM Run - Day 18
+    Part 1: Find shortest path from top left corner to bottom right corner in a grid where coordinates are getting corrupted by falling bytes.
+            Must avoid corrupted spots and stay within bounds.
+    Part 2: Find coordinates of the first falling byte that makes it impossible to reach the exit.
+*/
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 18;
+
+    type Parsed<'a> = Vec<Position>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_obstacles(input)
+    }
+
+    fn part1(obstacles: &Self::Parsed<'_>) -> Answer {
+        exercise1(obstacles, 1024).into()
+    }
+
+    fn part2(obstacles: &Self::Parsed<'_>) -> Answer {
+        exercise2(obstacles).into()
+    }
+}
+
+utils::register!(Day18);
+
+pub fn exercise1(obstacles: &[Position], obstacle_amount: usize) -> i64 {
+    let map = corrupted_map(obstacles, obstacle_amount);
+    let path = map.shortest_path().expect("No path to the goal found!");
+    path.len() as i64 - 1
+}
+
+pub fn exercise2(obstacles: &[Position]) -> Option<String> {
+    let map = Map::new(obstacles);
+    let max = Position::new(map.grid.height() - 1, map.grid.width() - 1);
+
+    let blocking = search::first_blocking(
+        map.start,
+        map.goal,
+        obstacles,
+        |pos| pos.neighbors_contained(max),
+        Strategy::UnionFind,
+    )?;
+    let res = obstacles[blocking];
+    Some(format!("{},{}", res.col, res.row))
+}
+
+/// Draws the memory after `obstacle_amount` bytes fell, with the shortest
+/// path to the exit highlighted.
+pub fn path_image(obstacles: &[Position], obstacle_amount: usize) -> Image {
+    let map = corrupted_map(obstacles, obstacle_amount);
+    let path: HashSet<Position> = map
+        .shortest_path()
+        .expect("No path to the goal found!")
+        .into_iter()
+        .collect();
+    map.grid.to_image(4, |pos, tile| match tile {
+        _ if path.contains(&pos) => PATH,
+        TileKind::Obstacle => OBSTACLE,
+        TileKind::Free => image::BLACK,
+    })
+}
+
+const PATH: Rgb = [255, 200, 0];
+const OBSTACLE: Rgb = [200, 40, 40];
+
+/// Returns the map with the first `obstacle_amount` bytes fallen.
+fn corrupted_map(obstacles: &[Position], obstacle_amount: usize) -> Map {
+    let mut map = Map::new(obstacles);
+
+    for obstacle in &obstacles[..min(obstacle_amount, obstacles.len())] {
+        map.grid.put(obstacle, TileKind::Obstacle);
+    }
+    map
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TileKind {
+    Obstacle,
+    Free,
+}
+
+impl std::fmt::Display for TileKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TileKind::Obstacle => '#',
+                TileKind::Free => '.',
+            }
+        )
+    }
+}
+
+struct Map {
+    grid: Grid<TileKind>,
+    start: Position,
+    goal: Position,
+}
+
+impl Map {
+    /// Creates a map without obstacles, just large enough to hold all of them.
+    fn new(obstacles: &[Position]) -> Self {
+        let rows = obstacles.iter().map(|pos| pos.row).max().unwrap() + 1;
+        let cols = obstacles.iter().map(|pos| pos.col).max().unwrap() + 1;
+        Self {
+            grid: Grid::filled(cols, rows, TileKind::Free),
+            start: Position::new(0, 0),
+            goal: Position::new(rows - 1, cols - 1),
+        }
+    }
+
+    fn shortest_path(&self) -> Option<Vec<Position>> {
+        search::bfs(
+            self.start,
+            |pos| {
+                pos.neighbors()
+                    .filter(|next| self.grid.get(next) == Some(&TileKind::Free))
+            },
+            |pos| *pos == self.goal,
+        )
+        .path()
+    }
+}
+
+fn parse_obstacles(input: &str) -> Vec<Position> {
+    input
+        .lines()
+        .filter_map(|line| {
+            line.split_once(',')
+                .map(|(col, row)| Position::new(row.parse().unwrap(), col.parse().unwrap()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day18::parse(&input), 12);
+            assert_eq!(res, 22);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day18::parse(&input), 1024);
+            assert_eq!(res, 334);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day18::parse(&input))
+                .expect("No obstacle prevents the exit from being reachable.");
+            assert_eq!(res, "6,1");
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day18::parse(&input))
+                .expect("No obstacle prevents the exit from being reachable.");
+            assert_eq!(res, "20,12");
+        }
+    }
+}
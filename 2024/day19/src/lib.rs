@@ -0,0 +1,148 @@
+use utils::{
+    memo::Memo,
+    parse,
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Linen Layout - Day 19
+    Part 1: Count how many towel designs are possible to make using given pattern pieces.
+    Part 2: Count total number of different ways each possible design can be made.
+*/
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 19;
+
+    type Parsed<'a> = (Vec<&'a str>, Vec<&'a str>);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Parsed<'_>) -> Answer {
+        exercise1(input).into()
+    }
+
+    fn part2(input: &Self::Parsed<'_>) -> Answer {
+        exercise2(input).into()
+    }
+}
+
+utils::register!(Day19);
+
+pub fn exercise1((available, wanted): &(Vec<&str>, Vec<&str>)) -> usize {
+    let mut memo = Memo::new();
+    let mut possible: usize = 0;
+
+    for &design in wanted {
+        let selected_available: Vec<&str> = available
+            .iter()
+            .filter(|&a| design.contains(a))
+            .copied()
+            .collect();
+        if is_possible(design, &selected_available, &mut memo) {
+            possible += 1;
+        }
+    }
+    possible
+}
+
+pub fn exercise2((available, wanted): &(Vec<&str>, Vec<&str>)) -> usize {
+    let mut memo = Memo::new();
+    let mut possible: usize = 0;
+
+    for &design in wanted {
+        let selected_available: Vec<&str> = available
+            .iter()
+            .filter(|&a| design.contains(a))
+            .copied()
+            .collect();
+        possible += count_possible(design, &selected_available, &mut memo);
+    }
+    possible
+}
+
+fn is_possible<'a>(wanted: &'a str, available: &[&str], memo: &mut Memo<&'a str, bool>) -> bool {
+    memo.compute(wanted, |is_possible, &wanted| {
+        wanted.is_empty()
+            || available
+                .iter()
+                .filter_map(|a| wanted.strip_prefix(a))
+                .any(is_possible)
+    })
+}
+
+fn count_possible<'a>(
+    wanted: &'a str,
+    available: &[&str],
+    memo: &mut Memo<&'a str, usize>,
+) -> usize {
+    memo.compute(wanted, |count_possible, &wanted| {
+        if wanted.is_empty() {
+            return 1;
+        }
+        available
+            .iter()
+            .filter_map(|a| wanted.strip_prefix(a))
+            .map(count_possible)
+            .sum()
+    })
+}
+
+fn parse_input(input: &str) -> (Vec<&str>, Vec<&str>) {
+    parse::parse_sections(input, (parse_available, parse_wanted)).or_panic()
+}
+
+fn parse_available(section: &str) -> utils::Result<Vec<&str>> {
+    Ok(section.split(", ").collect())
+}
+
+fn parse_wanted(section: &str) -> utils::Result<Vec<&str>> {
+    Ok(section.lines().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day19::parse(&input));
+            assert_eq!(res, 6);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day19::parse(&input));
+            assert_eq!(res, 226);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day19::parse(&input));
+            assert_eq!(res, 16);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day19::parse(&input));
+            assert_eq!(res, 601201576113503);
+        }
+    }
+}
@@ -0,0 +1,438 @@
+use itertools::Itertools;
+use std::{
+    fmt,
+    io::{self, IsTerminal},
+};
+use utils::{
+    colors,
+    grid::{Direction4, Grid, GridRenderer, Offset, Position},
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Race Condition - Day 20
+    Part 1: Find number of possible 2-tile wall-passing cheats that save >= 100 picoseconds vs normal path.
+    Part 2: Same as part 1, but with up to 20-tile wall-passing cheats.
+*/
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 20;
+
+    type Parsed<'a> = RaceTrack;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        RaceTrack::new(input)
+    }
+
+    fn part1(racetrack: &Self::Parsed<'_>) -> Answer {
+        exercise1(racetrack, 100).into()
+    }
+
+    fn part2(racetrack: &Self::Parsed<'_>) -> Answer {
+        exercise2(racetrack, 20, 100).into()
+    }
+}
+
+utils::register!(Day20);
+
+pub fn exercise1(racetrack: &RaceTrack, min_gain: usize) -> usize {
+    const MAX_CHEAT: usize = 2;
+
+    racetrack
+        .iter()
+        .map(|(pos, time)| {
+            Direction4::iter()
+                .filter_map(|dir| pos.offset(dir.into(), MAX_CHEAT as isize))
+                .filter_map(|cheat_pos| racetrack.time(&cheat_pos))
+                .filter(|cheat_time| cheat_time - time - MAX_CHEAT as i64 >= min_gain as i64)
+                .count()
+        })
+        .sum()
+}
+
+pub fn exercise2(racetrack: &RaceTrack, max_cheat: usize, min_gain: usize) -> usize {
+    racetrack
+        .iter()
+        .map(|(pos, time)| cheats_from(racetrack, pos, time, max_cheat, min_gain).count())
+        .sum()
+}
+
+/// Prints the track once for every position on it, with the cheats starting
+/// there highlighted.
+pub fn print_cheats(racetrack: &RaceTrack, max_cheat: usize, min_gain: usize) {
+    for (i, (pos, time)) in racetrack.iter().enumerate() {
+        let cheats: Vec<Position> =
+            cheats_from(racetrack, pos, time, max_cheat, min_gain).collect();
+        print_track_with_cheat_tiles(racetrack, pos, &cheats, i == 0);
+    }
+}
+
+/// Returns the end positions of the cheats starting at `pos` that save at
+/// least `min_gain` picoseconds.
+fn cheats_from(
+    racetrack: &RaceTrack,
+    pos: Position,
+    time: i64,
+    max_cheat: usize,
+    min_gain: usize,
+) -> impl Iterator<Item = Position> + '_ {
+    circular_neighbors(pos, max_cheat).filter(move |cheat_pos| {
+        racetrack.time(cheat_pos).is_some_and(|cheat_time| {
+            cheat_time - time - manhattan_distance(pos, *cheat_pos) as i64 >= min_gain as i64
+        })
+    })
+}
+
+/// Returns the positions at most `distance` steps away from `pos`.
+fn circular_neighbors(pos: Position, distance: usize) -> impl Iterator<Item = Position> {
+    itertools::repeat_n(-(distance as isize)..=distance as isize, 2)
+        .multi_cartesian_product()
+        .filter(move |diff| isize::abs(diff[0]) + isize::abs(diff[1]) <= distance as isize)
+        .filter_map(move |diff| pos.checked_add(Offset::new(diff[1], diff[0])))
+}
+
+fn manhattan_distance(a: Position, b: Position) -> usize {
+    a.row.abs_diff(b.row) + a.col.abs_diff(b.col)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum TrackTile {
+    Wall,
+    /// Reached `time` picoseconds after the start when following the track.
+    Track {
+        time: i64,
+    },
+}
+
+impl TryFrom<char> for TrackTile {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(TrackTile::Wall),
+            '.' | 'S' | 'E' => Ok(TrackTile::Track { time: 0 }),
+            _ => Err(c),
+        }
+    }
+}
+
+impl fmt::Display for TrackTile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TrackTile::Wall => '#',
+                TrackTile::Track { .. } => '.',
+            }
+        )
+    }
+}
+
+pub struct RaceTrack {
+    grid: Grid<TrackTile>,
+    /// The positions of the track in the order they are passed.
+    track: Vec<Position>,
+    start: Position,
+    finish: Position,
+}
+
+impl RaceTrack {
+    fn new(input: &str) -> Self {
+        let (grid, markers) = Grid::parse_markers(input, "SE").or_panic();
+        let mut racetrack = Self {
+            grid,
+            track: Vec::new(),
+            start: markers.get('S').expect("No start tile found!"),
+            finish: markers.get('E').expect("No end tile found!"),
+        };
+        racetrack.build_track();
+        racetrack
+    }
+
+    fn build_track(&mut self) {
+        let mut cur = self.start;
+        let mut prev = self.start;
+        let mut time = 0;
+
+        loop {
+            self.grid[cur] = TrackTile::Track { time };
+            self.track.push(cur);
+            let Some(next) = cur
+                .neighbors()
+                .filter(|next| self.time(next).is_some())
+                .find(|&next| next != prev)
+            else {
+                break;
+            };
+            prev = cur;
+            cur = next;
+            time += 1;
+        }
+    }
+
+    /// Returns when the tile at `pos` is reached, or `None` if it is not part
+    /// of the track.
+    fn time(&self, pos: &Position) -> Option<i64> {
+        match self.grid.get(pos)? {
+            TrackTile::Track { time } => Some(*time),
+            TrackTile::Wall => None,
+        }
+    }
+
+    /// Returns the positions of the track from start to finish, with the time
+    /// they are reached at.
+    fn iter(&self) -> impl Iterator<Item = (Position, i64)> + '_ {
+        self.track
+            .iter()
+            .map(|&pos| (pos, self.time(&pos).unwrap()))
+    }
+}
+
+fn print_track_with_cheat_tiles(
+    racetrack: &RaceTrack,
+    cur_pos: Position,
+    cheat_positions: &[Position],
+    first_time: bool,
+) {
+    let rendered = GridRenderer::new(&racetrack.grid)
+        .marker(racetrack.start, 'S', colors::BOLD_BRIGHT_CYAN)
+        .marker(racetrack.finish, 'E', colors::BOLD_BRIGHT_CYAN)
+        .marker(cur_pos, 'I', colors::BOLD_BRIGHT_YELLOW)
+        .markers(
+            cheat_positions.iter().map(|&pos| (pos, 'O')),
+            colors::BOLD_BRIGHT_GREEN,
+        )
+        .to_string();
+
+    // Draws over the previous track in a terminal, so that it animates.
+    if !first_time && io::stdout().is_terminal() {
+        print!("\x1B[{}A", racetrack.grid.height());
+    }
+    print!("{}", rendered);
+}
+
+impl fmt::Display for RaceTrack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (pos, tile) in self.grid.iter() {
+            if pos == self.start {
+                write!(f, "S")?;
+            } else if pos == self.finish {
+                write!(f, "E")?;
+            } else {
+                write!(f, "{}", tile)?;
+            }
+            if pos.col + 1 == self.grid.width() {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example_min2() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 2);
+            assert_eq!(res, 44);
+        }
+
+        #[test]
+        fn example_min4() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 4);
+            assert_eq!(res, 30);
+        }
+
+        #[test]
+        fn example_min6() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 6);
+            assert_eq!(res, 16);
+        }
+
+        #[test]
+        fn example_min8() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 8);
+            assert_eq!(res, 14);
+        }
+
+        #[test]
+        fn example_min10() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 10);
+            assert_eq!(res, 10);
+        }
+
+        #[test]
+        fn example_min12() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 12);
+            assert_eq!(res, 8);
+        }
+
+        #[test]
+        fn example_min20() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 20);
+            assert_eq!(res, 5);
+        }
+
+        #[test]
+        fn example_min36() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 36);
+            assert_eq!(res, 4);
+        }
+
+        #[test]
+        fn example_min38() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 38);
+            assert_eq!(res, 3);
+        }
+
+        #[test]
+        fn example_min40() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 40);
+            assert_eq!(res, 2);
+        }
+
+        #[test]
+        fn example_min64() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day20::parse(&input), 64);
+            assert_eq!(res, 1);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day20::parse(&input), 100);
+            assert_eq!(res, 1445);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn example_min50() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 50);
+            assert_eq!(res, 285);
+        }
+
+        #[test]
+        fn example_min52() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 52);
+            assert_eq!(res, 253);
+        }
+
+        #[test]
+        fn example_min54() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 54);
+            assert_eq!(res, 222);
+        }
+
+        #[test]
+        fn example_min56() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 56);
+            assert_eq!(res, 193);
+        }
+
+        #[test]
+        fn example_min58() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 58);
+            assert_eq!(res, 154);
+        }
+
+        #[test]
+        fn example_min60() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 60);
+            assert_eq!(res, 129);
+        }
+
+        #[test]
+        fn example_min62() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 62);
+            assert_eq!(res, 106);
+        }
+
+        #[test]
+        fn example_min64() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 64);
+            assert_eq!(res, 86);
+        }
+
+        #[test]
+        fn example_min66() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 66);
+            assert_eq!(res, 67);
+        }
+
+        #[test]
+        fn example_min68() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 68);
+            assert_eq!(res, 55);
+        }
+
+        #[test]
+        fn example_min70() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 70);
+            assert_eq!(res, 41);
+        }
+
+        #[test]
+        fn example_min72() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 72);
+            assert_eq!(res, 29);
+        }
+
+        #[test]
+        fn example_min74() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 74);
+            assert_eq!(res, 7);
+        }
+
+        #[test]
+        fn example_min76() {
+            let input = input::read_file("example.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 76);
+            assert_eq!(res, 3);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day20::parse(&input), 20, 100);
+            assert_eq!(res, 1008040);
+        }
+    }
+}
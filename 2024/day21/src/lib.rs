@@ -0,0 +1,382 @@
+use std::{fmt, iter};
+use utils::{
+    grid::Vec2,
+    memo::Memo,
+    parse,
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Keypad Conundrum - Day 21
+    Part 1: Control a chain of 3 robots (using directional keypads) to input codes on a numeric keypad.
+            Calculate sum of complexities (sequence_length * numeric_code) of the codes at the end of the chain.
+    Part 2: Same as part 1, but with 26 robots in the chain instead of 3.
+*/
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 21;
+
+    type Parsed<'a> = Vec<Code>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_codes(input)
+    }
+
+    fn part1(codes: &Self::Parsed<'_>) -> Answer {
+        exercise(codes, 2).into()
+    }
+
+    fn part2(codes: &Self::Parsed<'_>) -> Answer {
+        exercise(codes, 25).into()
+    }
+}
+
+utils::register!(Day21);
+
+pub fn exercise(codes: &[Code], indirections: u32) -> usize {
+    let mut res = 0;
+    let mut numpad = KeyPad::new(NumKey::Activate);
+    let mut memo = Memo::new();
+
+    for code in codes {
+        let mut len: usize = 0;
+
+        for &button in &code.keys {
+            len += steps(&numpad.press(button))
+                .map(|(from, to)| remote_control(&mut memo, from, to, indirections))
+                .sum::<usize>();
+        }
+        res += len * code.number;
+    }
+    res
+}
+
+/// A door code: the keys to type on the numeric keypad and its numeric part.
+pub struct Code {
+    keys: Vec<NumKey>,
+    number: usize,
+}
+
+fn parse_codes(input: &str) -> Vec<Code> {
+    input
+        .lines()
+        .map(|line| {
+            let [number] = parse::n_numbers::<usize, 1>(line).or_panic();
+            Code {
+                keys: line.chars().map(NumKey::from).collect(),
+                number,
+            }
+        })
+        .collect()
+}
+
+/// Returns how many buttons have to be pressed to make a directional keypad
+/// move from `from` to `to` and press it, when `indirections` robots are in
+/// between. Without robots, that is the single press of `to` itself.
+fn remote_control(
+    memo: &mut Memo<(DirKey, DirKey, u32), usize>,
+    from: DirKey,
+    to: DirKey,
+    indirections: u32,
+) -> usize {
+    memo.compute(
+        (from, to, indirections),
+        |remote_control, &(from, to, indirections)| {
+            if indirections == 0 {
+                return 1;
+            }
+            steps(&KeyPad::new(from).press(to))
+                .map(|(from, to)| remote_control((from, to, indirections - 1)))
+                .sum()
+        },
+    )
+}
+
+/// Returns the moves between consecutive buttons of `dir_code`, starting at
+/// the activate button.
+fn steps(dir_code: &[DirKey]) -> impl Iterator<Item = (DirKey, DirKey)> + '_ {
+    iter::once(DirKey::Activate)
+        .chain(dir_code.iter().copied())
+        .zip(dir_code.iter().copied())
+}
+
+struct KeyPad<T: Key> {
+    current: T,
+}
+
+impl<T: Key> KeyPad<T> {
+    fn new(initial: T) -> KeyPad<T> {
+        KeyPad { current: initial }
+    }
+
+    fn press(&mut self, target: T) -> Vec<DirKey> {
+        let mut movements: Vec<DirKey> = Vec::new();
+        let diff = target.pos() - self.current.pos();
+        let (col_diff, row_diff) = (diff.x, diff.y);
+
+        for _ in 0..col_diff.abs() {
+            movements.push(if col_diff.is_positive() {
+                DirKey::Right
+            } else {
+                DirKey::Left
+            });
+        }
+        for _ in 0..row_diff.abs() {
+            movements.push(if row_diff.is_positive() {
+                DirKey::Down
+            } else {
+                DirKey::Up
+            });
+        }
+
+        if col_diff.is_positive() {
+            movements.reverse();
+        }
+        if !self.current.is_valid(&movements) {
+            movements.reverse();
+        }
+
+        movements.push(DirKey::Activate);
+        self.current = target;
+        movements
+    }
+}
+
+trait Key: Copy {
+    /// The position of the key on its keypad, with `y` growing downwards.
+    fn pos(&self) -> Vec2<i8>;
+    fn at(pos: Vec2<i8>) -> Option<Self>;
+    fn to(&self, dir: DirKey) -> Option<Self> {
+        Self::at(self.pos() + dir.offset())
+    }
+    fn is_valid(&self, movements: &[DirKey]) -> bool {
+        let mut cur = *self;
+        for &movement in movements {
+            if let Some(key) = cur.to(movement) {
+                cur = key;
+            } else {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Clone, Copy)]
+enum NumKey {
+    Zero,
+    One,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Activate,
+}
+
+impl Key for NumKey {
+    fn pos(&self) -> Vec2<i8> {
+        use NumKey::*;
+        let row: i8 = match self {
+            Seven | Eight | Nine => 0,
+            Four | Five | Six => 1,
+            One | Two | Three => 2,
+            Zero | Activate => 3,
+        };
+        let col: i8 = match self {
+            One | Four | Seven => 0,
+            Zero | Two | Five | Eight => 1,
+            Activate | Three | Six | Nine => 2,
+        };
+        Vec2::new(col, row)
+    }
+
+    fn at(pos: Vec2<i8>) -> Option<NumKey> {
+        use NumKey::*;
+        match pos {
+            p if p == Zero.pos() => Some(Zero),
+            p if p == One.pos() => Some(One),
+            p if p == Two.pos() => Some(Two),
+            p if p == Three.pos() => Some(Three),
+            p if p == Four.pos() => Some(Four),
+            p if p == Five.pos() => Some(Five),
+            p if p == Six.pos() => Some(Six),
+            p if p == Seven.pos() => Some(Seven),
+            p if p == Eight.pos() => Some(Eight),
+            p if p == Nine.pos() => Some(Nine),
+            p if p == Activate.pos() => Some(Activate),
+            _ => None,
+        }
+    }
+}
+
+impl From<char> for NumKey {
+    fn from(c: char) -> NumKey {
+        use NumKey::*;
+        match c {
+            '0' => Zero,
+            '1' => One,
+            '2' => Two,
+            '3' => Three,
+            '4' => Four,
+            '5' => Five,
+            '6' => Six,
+            '7' => Seven,
+            '8' => Eight,
+            '9' => Nine,
+            'A' => Activate,
+            _ => panic!("Invalid NumPad character found!"),
+        }
+    }
+}
+
+impl fmt::Display for NumKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use NumKey::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Zero => '0',
+                One => '1',
+                Two => '2',
+                Three => '3',
+                Four => '4',
+                Five => '5',
+                Six => '6',
+                Seven => '7',
+                Eight => '8',
+                Nine => '9',
+                Activate => 'A',
+            }
+        )
+    }
+}
+
+impl fmt::Debug for NumKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self, f)
+    }
+}
+
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+enum DirKey {
+    Right,
+    Up,
+    Down,
+    Left,
+    Activate,
+}
+
+impl Key for DirKey {
+    fn pos(&self) -> Vec2<i8> {
+        use DirKey::*;
+        let row: i8 = match self {
+            Up | Activate => 0,
+            Left | Down | Right => 1,
+        };
+        let col: i8 = match self {
+            Left => 0,
+            Up | Down => 1,
+            Right | Activate => 2,
+        };
+        Vec2::new(col, row)
+    }
+
+    fn at(pos: Vec2<i8>) -> Option<DirKey> {
+        use DirKey::*;
+        match pos {
+            p if p == Right.pos() => Some(Right),
+            p if p == Up.pos() => Some(Up),
+            p if p == Down.pos() => Some(Down),
+            p if p == Left.pos() => Some(Left),
+            p if p == Activate.pos() => Some(Activate),
+            _ => None,
+        }
+    }
+}
+
+impl DirKey {
+    fn offset(&self) -> Vec2<i8> {
+        use DirKey::*;
+        match self {
+            Right => Vec2::new(1, 0),
+            Up => Vec2::new(0, -1),
+            Down => Vec2::new(0, 1),
+            Left => Vec2::new(-1, 0),
+            Activate => Vec2::new(0, 0),
+        }
+    }
+}
+
+impl fmt::Display for DirKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use DirKey::*;
+        write!(
+            f,
+            "{}",
+            match self {
+                Right => '>',
+                Up => '^',
+                Down => 'v',
+                Left => '<',
+                Activate => 'A',
+            }
+        )
+    }
+}
+
+impl fmt::Debug for DirKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise(&Day21::parse(&input), 2);
+            assert_eq!(res, 126384);
+        }
+
+        #[test]
+        fn fewer_robots() {
+            let codes = Day21::parse("029A");
+            assert_eq!(exercise(&codes, 0), 12 * 29);
+            assert_eq!(exercise(&codes, 1), 28 * 29);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise(&Day21::parse(&input), 2);
+            assert_eq!(res, 219366);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise(&Day21::parse(&input), 25);
+            assert_eq!(res, 271631192020464);
+        }
+    }
+}
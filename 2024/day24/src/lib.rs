@@ -0,0 +1,369 @@
+use std::{collections::HashMap, str::FromStr};
+use utils::{
+    parse,
+    solution::{Answer, Solution},
+    OrPanic,
+};
+
+/*
+    Crossed Wires - Day 24
+    Part 1: Simulate boolean logic gates (AND, OR, XOR) with initial values on x-wires and y-wires to compute final decimal number on z-wires.
+    Part 2: Find 4 pairs of swapped gate outputs to make the circuit correctly perform binary addition.
+*/
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 24;
+
+    type Parsed<'a> = HashMap<String, Gate>;
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_gates(input)
+    }
+
+    fn part1(gates: &Self::Parsed<'_>) -> Answer {
+        exercise1(gates).into()
+    }
+
+    fn part2(gates: &Self::Parsed<'_>) -> Answer {
+        exercise2(gates).into()
+    }
+}
+
+utils::register!(Day24);
+
+pub fn exercise1(gates: &HashMap<String, Gate>) -> usize {
+    get_combined_number(gates, 'z')
+}
+
+pub fn exercise2(gates: &HashMap<String, Gate>) -> String {
+    let mut bad_gates: Vec<String> = collect_bad_gates(gates)
+        .iter()
+        .map(|gate| gate.out.clone())
+        .collect();
+    bad_gates.sort();
+    bad_gates.join(",")
+}
+
+/*
+    I know that only OUTPUT wires have been swapped, NOT input wires!
+
+    z00 XOR
+        x00
+        y00
+
+    z01 XOR
+        XOR
+            x01
+            y01
+        AND
+            x00
+            y00
+
+    zn XOR
+        XOR
+            xn
+            yn
+        OR
+            AND
+                xn-1
+                yn-1
+            AND
+                same as zn-1
+
+    z45 OR
+        AND
+            x44
+            y44
+        AND
+            same as z44
+*/
+fn collect_bad_gates(gates: &HashMap<String, Gate>) -> Vec<&GateData> {
+    let bad_xor_gates = collect_bad_xor_gates(gates);
+    let bad_and_gates = collect_bad_and_gates(gates);
+    let bad_or_gates = collect_bad_or_gates(gates);
+
+    bad_xor_gates
+        .into_iter()
+        .chain(bad_and_gates)
+        .chain(bad_or_gates)
+        .collect()
+}
+
+// Either both inputs contain digit, or output contains digit (except 00)
+fn collect_bad_xor_gates(gates: &HashMap<String, Gate>) -> Vec<&GateData> {
+    let mut bad_gates = Vec::new();
+
+    for gate in gates.values().filter_map(|gate| match gate {
+        Gate::Normal(data) if data.op == Operator::Xor => Some(data),
+        _ => None,
+    }) {
+        if gate.in1.ends_with("00") && gate.in2.ends_with("00") {
+            if !gate.out.ends_with("00") {
+                bad_gates.push(gate);
+            }
+            continue;
+        }
+        let is_input1 = gate.in1.chars().any(|c| c.is_ascii_digit());
+        let is_input2 = gate.in2.chars().any(|c| c.is_ascii_digit());
+        let is_output = gate.out.chars().any(|c| c.is_ascii_digit());
+
+        match (is_input1, is_input2, is_output) {
+            (true, true, false) => {
+                let outputs = outputs_to(gates, &gate.out).collect::<Vec<&GateData>>();
+                if outputs.len() != 2
+                    || count_operator(&outputs, Operator::Xor) != 1
+                    || count_operator(&outputs, Operator::And) != 1
+                {
+                    bad_gates.push(gate);
+                }
+            }
+            (false, false, true) => (),
+            _ => bad_gates.push(gate),
+        }
+    }
+    bad_gates
+}
+
+// Output to OR (except if inputs are 00)
+fn collect_bad_and_gates(gates: &HashMap<String, Gate>) -> Vec<&GateData> {
+    let mut bad_gates = Vec::new();
+
+    for gate in gates.values().filter_map(|gate| match gate {
+        Gate::Normal(data) if data.op == Operator::And => Some(data),
+        _ => None,
+    }) {
+        let outputs = outputs_to(gates, &gate.out).collect::<Vec<&GateData>>();
+        if gate.in1.ends_with("00") && gate.in2.ends_with("00") {
+            if outputs.len() != 2
+                || count_operator(&outputs, Operator::Xor) != 1
+                || count_operator(&outputs, Operator::And) != 1
+            {
+                bad_gates.push(gate);
+            }
+            continue;
+        }
+        if outputs.len() != 1 || count_operator(&outputs, Operator::Or) != 1 {
+            bad_gates.push(gate);
+        }
+    }
+    bad_gates
+}
+
+// Output to 1 XOR and 1 AND (except for last, which outputs as last bit)
+fn collect_bad_or_gates(gates: &HashMap<String, Gate>) -> Vec<&GateData> {
+    let mut bad_gates = Vec::new();
+
+    for gate in gates.values().filter_map(|gate| match gate {
+        Gate::Normal(data) if data.op == Operator::Or => Some(data),
+        _ => None,
+    }) {
+        if let Some(bit_pos) = gate.bit_pos() {
+            if bit_pos as usize == gates.values().filter(|g| g.is_input()).count() / 2 {
+                continue;
+            }
+        }
+        let outputs = outputs_to(gates, &gate.out).collect::<Vec<&GateData>>();
+        if outputs.len() != 2
+            || count_operator(&outputs, Operator::Xor) != 1
+            || count_operator(&outputs, Operator::And) != 1
+        {
+            bad_gates.push(gate);
+        }
+    }
+    bad_gates
+}
+
+fn outputs_to<'a>(
+    gates: &'a HashMap<String, Gate>,
+    from: &'a str,
+) -> impl Iterator<Item = &'a GateData> {
+    gates.values().filter_map(move |gate| match gate {
+        Gate::Normal(data) if data.in1 == from || data.in2 == from => Some(data),
+        _ => None,
+    })
+}
+
+fn count_operator(outputs: &[&GateData], op: Operator) -> usize {
+    outputs.iter().filter(|gate| gate.op == op).count()
+}
+
+fn get_combined_number(gates: &HashMap<String, Gate>, gate_letter: char) -> usize {
+    let mut num: usize = 0;
+
+    for (key, gate) in gates.iter().filter(|(key, _)| key.starts_with(gate_letter)) {
+        num |= (get_value(gates, key) as usize) << gate.bit_pos().unwrap_or_default();
+    }
+    num
+}
+
+fn get_value(gates: &HashMap<String, Gate>, target: &str) -> u8 {
+    match &gates[target] {
+        Gate::Input(data) => data.value,
+        Gate::Normal(data) => data
+            .op
+            .calc(get_value(gates, &data.in1), get_value(gates, &data.in2)),
+    }
+}
+
+pub enum Gate {
+    Input(InputData),
+    Normal(GateData),
+}
+
+impl Gate {
+    fn name(&self) -> &str {
+        match self {
+            Gate::Input(data) => data.name.as_str(),
+            Gate::Normal(data) => data.out.as_str(),
+        }
+    }
+
+    fn bit_pos(&self) -> Option<u8> {
+        match self {
+            Gate::Input(data) => Some(data.bit_pos),
+            Gate::Normal(data) => data.bit_pos(),
+        }
+    }
+
+    fn is_input(&self) -> bool {
+        match self {
+            Gate::Input(_) => true,
+            Gate::Normal(_) => false,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GateData {
+    op: Operator,
+    in1: String,
+    in2: String,
+    out: String,
+}
+
+impl GateData {
+    fn new(line: &str) -> utils::Result<GateData> {
+        let (in1, op, in2, out) = parse!("{} {} {} -> {}", line)?;
+        Ok(GateData { op, in1, in2, out })
+    }
+
+    fn bit_pos(&self) -> Option<u8> {
+        parse::numbers::<u8>(&self.out)
+            .next()
+            .transpose()
+            .or_panic()
+    }
+}
+
+pub struct InputData {
+    name: String,
+    bit_pos: u8,
+    value: u8,
+}
+
+impl InputData {
+    fn new(line: &str) -> utils::Result<InputData> {
+        let (name, value): (String, u8) = parse!("{}: {}", line)?;
+        let [bit_pos] = parse::n_numbers(&name)?;
+        Ok(InputData {
+            name,
+            bit_pos,
+            value,
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+enum Operator {
+    And,
+    Or,
+    Xor,
+}
+
+impl Operator {
+    fn calc(&self, in1: u8, in2: u8) -> u8 {
+        match self {
+            Operator::And => in1 & in2,
+            Operator::Or => in1 | in2,
+            Operator::Xor => in1 ^ in2,
+        }
+    }
+}
+
+impl FromStr for Operator {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AND" => Ok(Operator::And),
+            "OR" => Ok(Operator::Or),
+            "XOR" => Ok(Operator::Xor),
+            _ => Err(format!("Unknown operator {:?}", s)),
+        }
+    }
+}
+
+fn parse_gates(input: &str) -> HashMap<String, Gate> {
+    let (inputs, gates) =
+        parse::parse_sections(input, (parse_inputs, parse_logic_gates)).or_panic();
+
+    inputs
+        .into_iter()
+        .map(Gate::Input)
+        .chain(gates.into_iter().map(Gate::Normal))
+        .map(|gate| (gate.name().to_owned(), gate))
+        .collect()
+}
+
+fn parse_inputs(section: &str) -> utils::Result<Vec<InputData>> {
+    section.lines().map(InputData::new).collect()
+}
+
+fn parse_logic_gates(section: &str) -> utils::Result<Vec<GateData>> {
+    section.lines().map(GateData::new).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise1 {
+        use super::*;
+
+        #[test]
+        fn example1() {
+            let input = input::read_file("example1.txt");
+            let res = exercise1(&Day24::parse(&input));
+            assert_eq!(res, 4);
+        }
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise1(&Day24::parse(&input));
+            assert_eq!(res, 2024);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise1(&Day24::parse(&input));
+            assert_eq!(res, 59619940979346);
+        }
+    }
+
+    mod exercise2 {
+        use super::*;
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise2(&Day24::parse(&input));
+            assert_eq!(res, "bpt,fkp,krj,mfm,ngr,z06,z11,z31");
+        }
+    }
+}
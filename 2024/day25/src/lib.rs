@@ -0,0 +1,97 @@
+use utils::{
+    parse,
+    solution::{Answer, Solution},
+};
+
+/*
+    Code Chronicle - Day 25
+    Part 1: Analyze lock/key schematics to find how many unique pairs fit together without overlap.
+            Each lock/key is represented as columns of '#' extending down/up.
+    Part 2: No actual puzzle - just a story conclusion. Free star after completing all previous 49 puzzles.
+*/
+
+pub struct Day25;
+
+// The last day only has a single part.
+impl Solution for Day25 {
+    const YEAR: u16 = 2024;
+    const DAY: u8 = 25;
+
+    type Parsed<'a> = (Vec<Vec<u8>>, Vec<Vec<u8>>, u8);
+
+    fn parse(input: &str) -> Self::Parsed<'_> {
+        parse_locks_and_keys(input)
+    }
+
+    fn part1(schematics: &Self::Parsed<'_>) -> Answer {
+        exercise(schematics).into()
+    }
+}
+
+utils::register!(Day25);
+
+pub fn exercise((locks, keys, height): &(Vec<Vec<u8>>, Vec<Vec<u8>>, u8)) -> usize {
+    let mut res = 0;
+
+    for lock in locks {
+        for key in keys {
+            if lock.iter().zip(key.iter()).all(|(&l, &k)| l + k <= *height) {
+                res += 1;
+            }
+        }
+    }
+    res
+}
+
+fn parse_locks_and_keys(input: &str) -> (Vec<Vec<u8>>, Vec<Vec<u8>>, u8) {
+    let mut locks: Vec<Vec<u8>> = Vec::new();
+    let mut keys: Vec<Vec<u8>> = Vec::new();
+    let first = parse::sections(input).next().expect("No schematics found!");
+    let height = first.lines().count();
+    let width = first.find('\n').unwrap();
+
+    for block in parse::sections(input).map(|block| {
+        block
+            .lines()
+            .map(|line| line.chars().collect())
+            .collect::<Vec<Vec<char>>>()
+    }) {
+        let mut pins: Vec<u8> = Vec::new();
+
+        for col in 0..width {
+            let pin = block.iter().filter(|row| row[col] == '#').count();
+            pins.push(pin as u8);
+        }
+
+        if block[0][0] == '#' {
+            locks.push(pins);
+        } else {
+            keys.push(pins);
+        }
+    }
+    (locks, keys, height as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::input;
+
+    mod exercise {
+        use super::*;
+
+        #[test]
+        fn example() {
+            let input = input::read_file("example.txt");
+            let res = exercise(&Day25::parse(&input));
+            assert_eq!(res, 3);
+        }
+
+        #[test]
+        fn answer() {
+            let input = input::read_file("input.txt");
+            let res = exercise(&Day25::parse(&input));
+            assert_eq!(res, 3116);
+        }
+    }
+}
@@ -0,0 +1,370 @@
+//! Plays a stream of frames, e.g. the states of a grid simulation, in the
+//! terminal.
+//!
+//! Keys while playing:
+//!
+//! - `space`: pause and resume
+//! - `→`/`l` and `←`/`h`: step one frame forward and back, pausing first
+//! - `+` and `-`: double and halve the speed
+//! - `q`/`esc`/`ctrl+c`: quit
+//!
+//! Frames are rendered when they are first shown and kept, so that stepping
+//! back is possible. If stdout is not a terminal, the frames are printed one
+//! after another instead.
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue, terminal,
+};
+use std::fmt::Display;
+use std::io::{self, IsTerminal, Write};
+use std::time::Duration;
+
+const MIN_FPS: f64 = 0.25;
+const MAX_FPS: f64 = 1000.0;
+
+/// A key press understood by the player.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    TogglePause,
+    StepForward,
+    StepBack,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Command {
+    fn from_key(key: KeyEvent) -> Option<Self> {
+        match key.code {
+            // Raw mode turns ctrl+c into a key instead of a signal.
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                Some(Command::Quit)
+            }
+            KeyCode::Char(' ') => Some(Command::TogglePause),
+            KeyCode::Right | KeyCode::Char('l') => Some(Command::StepForward),
+            KeyCode::Left | KeyCode::Char('h') => Some(Command::StepBack),
+            KeyCode::Char('+') => Some(Command::Faster),
+            KeyCode::Char('-') => Some(Command::Slower),
+            KeyCode::Char('q') | KeyCode::Esc => Some(Command::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// The state of a player independent of the terminal: the frames seen so
+/// far, the current one, and whether and how fast it is playing.
+pub struct Playback<I> {
+    frames: I,
+    seen: Vec<String>,
+    current: usize,
+    ended: bool,
+    paused: bool,
+    fps: f64,
+    quit: bool,
+}
+
+impl<I> Playback<I>
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    pub fn new(frames: impl IntoIterator<IntoIter = I>, fps: f64) -> Self {
+        let mut playback = Self {
+            frames: frames.into_iter(),
+            seen: Vec::new(),
+            current: 0,
+            ended: false,
+            paused: false,
+            fps: fps.clamp(MIN_FPS, MAX_FPS),
+            quit: false,
+        };
+        playback.load_next();
+        playback
+    }
+
+    /// Returns the current frame, or `None` if there are no frames at all.
+    pub fn frame(&self) -> Option<&str> {
+        self.seen.get(self.current).map(String::as_str)
+    }
+
+    /// The number of the current frame, starting at 0.
+    pub fn index(&self) -> usize {
+        self.current
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Whether the current frame is the last one.
+    pub fn is_at_end(&self) -> bool {
+        self.ended && self.current + 1 >= self.seen.len()
+    }
+
+    pub fn is_quit(&self) -> bool {
+        self.quit
+    }
+
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    /// How long the current frame is shown while playing.
+    pub fn frame_time(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+
+    /// Moves to the next frame, returns false at the end.
+    pub fn advance(&mut self) -> bool {
+        if self.current + 1 >= self.seen.len() && !self.load_next() {
+            return false;
+        }
+        self.current += 1;
+        true
+    }
+
+    pub fn apply(&mut self, command: Command) {
+        match command {
+            Command::TogglePause => self.paused = !self.paused,
+            Command::StepForward => {
+                self.paused = true;
+                self.advance();
+            }
+            Command::StepBack => {
+                self.paused = true;
+                self.current = self.current.saturating_sub(1);
+            }
+            Command::Faster => self.fps = (self.fps * 2.0).min(MAX_FPS),
+            Command::Slower => self.fps = (self.fps / 2.0).max(MIN_FPS),
+            Command::Quit => self.quit = true,
+        }
+    }
+
+    /// A line with the frame counter and the state of the player.
+    pub fn status(&self) -> String {
+        let state = if self.is_at_end() {
+            "end"
+        } else if self.paused {
+            "paused"
+        } else {
+            "playing"
+        };
+        format!(
+            "frame {}  {} fps  {}  [space] pause  [←/→] step  [+/-] speed  [q] quit",
+            self.current, self.fps, state
+        )
+    }
+
+    fn load_next(&mut self) -> bool {
+        if self.ended {
+            return false;
+        }
+        match self.frames.next() {
+            Some(frame) => {
+                self.seen.push(frame.to_string());
+                true
+            }
+            None => {
+                self.ended = true;
+                false
+            }
+        }
+    }
+}
+
+/// Plays frames in the terminal, see the [module documentation](self).
+pub struct Player<I> {
+    playback: Playback<I>,
+}
+
+impl<I> Player<I>
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    /// Creates a player that starts playing with 10 frames per second.
+    pub fn new(frames: impl IntoIterator<IntoIter = I>) -> Self {
+        Self {
+            playback: Playback::new(frames, 10.0),
+        }
+    }
+
+    pub fn with_fps(mut self, fps: f64) -> Self {
+        self.playback.fps = fps.clamp(MIN_FPS, MAX_FPS);
+        self
+    }
+
+    /// Starts paused, e.g. to step through the first frames.
+    pub fn paused(mut self) -> Self {
+        self.playback.paused = true;
+        self
+    }
+
+    /// Plays until the user quits. The player stops at the last frame and
+    /// waits, so that it can still be inspected.
+    pub fn play(mut self) -> io::Result<()> {
+        if !io::stdout().is_terminal() {
+            return self.print();
+        }
+
+        let mut stdout = io::stdout();
+        let _guard = Terminal::enter(&mut stdout)?;
+        while !self.playback.is_quit() {
+            self.draw(&mut stdout)?;
+            let timeout = if self.playback.is_paused() || self.playback.is_at_end() {
+                None
+            } else {
+                Some(self.playback.frame_time())
+            };
+            match wait_for_key(timeout)? {
+                Some(command) => self.playback.apply(command),
+                None => {
+                    self.playback.advance();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        queue!(
+            out,
+            cursor::MoveTo(0, 0),
+            terminal::Clear(terminal::ClearType::All)
+        )?;
+        for line in self.playback.frame().unwrap_or_default().lines() {
+            write!(out, "{}\r\n", line)?;
+        }
+        write!(out, "\r\n{}", self.playback.status())?;
+        out.flush()
+    }
+
+    /// Prints all frames, for when there is no terminal to animate in.
+    fn print(mut self) -> io::Result<()> {
+        let mut stdout = io::stdout().lock();
+        while let Some(frame) = self.playback.frame() {
+            writeln!(stdout, "frame {}:\n{}", self.playback.index(), frame)?;
+            if !self.playback.advance() {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Waits for a key press for at most `timeout`, or forever if it is `None`.
+fn wait_for_key(timeout: Option<Duration>) -> io::Result<Option<Command>> {
+    loop {
+        if let Some(timeout) = timeout {
+            if !event::poll(timeout)? {
+                return Ok(None);
+            }
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind != KeyEventKind::Release {
+                if let Some(command) = Command::from_key(key) {
+                    return Ok(Some(command));
+                }
+            }
+        }
+    }
+}
+
+/// Switches the terminal into raw mode on an alternate screen, and back when
+/// dropped, also if playing fails.
+struct Terminal;
+
+impl Terminal {
+    fn enter(out: &mut impl Write) -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys() {
+        let key = |code, modifiers| Command::from_key(KeyEvent::new(code, modifiers));
+        assert_eq!(
+            key(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            Some(Command::Quit)
+        );
+        assert_eq!(key(KeyCode::Char('c'), KeyModifiers::NONE), None);
+        assert_eq!(key(KeyCode::Esc, KeyModifiers::NONE), Some(Command::Quit));
+        assert_eq!(
+            key(KeyCode::Char(' '), KeyModifiers::NONE),
+            Some(Command::TogglePause)
+        );
+    }
+
+    #[test]
+    fn steps() {
+        let mut playback = Playback::new(0..3, 10.0);
+        assert_eq!(playback.frame(), Some("0"));
+        assert!(playback.advance());
+        assert_eq!(playback.frame(), Some("1"));
+
+        playback.apply(Command::StepBack);
+        assert!(playback.is_paused());
+        assert_eq!(playback.frame(), Some("0"));
+        playback.apply(Command::StepBack);
+        assert_eq!(playback.index(), 0);
+
+        playback.apply(Command::StepForward);
+        playback.apply(Command::StepForward);
+        assert_eq!(playback.frame(), Some("2"));
+        assert!(!playback.is_at_end());
+        assert!(!playback.advance());
+        assert!(playback.is_at_end());
+        assert_eq!(playback.frame(), Some("2"));
+        assert!(playback.status().starts_with("frame 2  10 fps  end"));
+    }
+
+    #[test]
+    fn frames_are_rendered_once() {
+        let mut rendered = 0;
+        let frames = (0..5).inspect(|_| rendered += 1);
+        let mut playback = Playback::new(frames, 10.0);
+        playback.advance();
+        playback.apply(Command::StepBack);
+        playback.advance();
+        drop(playback);
+        assert_eq!(rendered, 2);
+    }
+
+    #[test]
+    fn speed() {
+        let mut playback = Playback::new(["a"], 10.0);
+        playback.apply(Command::Faster);
+        assert_eq!(playback.frame_time(), Duration::from_millis(50));
+        for _ in 0..20 {
+            playback.apply(Command::Slower);
+        }
+        assert_eq!(playback.fps(), MIN_FPS);
+        playback.apply(Command::TogglePause);
+        assert!(playback.is_paused());
+        playback.apply(Command::Quit);
+        assert!(playback.is_quit());
+    }
+
+    #[test]
+    fn no_frames() {
+        let mut playback = Playback::new(Vec::<String>::new(), 10.0);
+        assert_eq!(playback.frame(), None);
+        assert!(!playback.advance());
+        assert!(playback.is_at_end());
+    }
+}
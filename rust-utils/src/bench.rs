@@ -0,0 +1,249 @@
+use crate::solution::{Part, Solution};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// How often a stage is run when benchmarking it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// Runs before measuring, which are not part of the statistics. The first
+    /// run always happens.
+    pub warmup: usize,
+    /// Measured runs.
+    pub runs: usize,
+    /// Stops early once a stage took this long, so that slow days do not
+    /// take forever. A single run that takes longer than this is used as the
+    /// only sample.
+    pub max_time: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            runs: 20,
+            max_time: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Statistics of the measured runs of a stage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "No samples to compute statistics of!");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let variance = sorted
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Self {
+            runs: n,
+            min: sorted[0],
+            max: sorted[n - 1],
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timings of the stages of a [`Solution`]. Unsolved parts are left out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+/// Runs `f` according to the config and returns its last result together
+/// with the statistics of the measured runs.
+pub fn measure<T>(config: &Config, mut f: impl FnMut() -> T) -> (T, Stats) {
+    let start = Instant::now();
+    let mut samples = Vec::with_capacity(config.runs);
+    let mut run = || {
+        let start = Instant::now();
+        let res = black_box(f());
+        (res, start.elapsed())
+    };
+
+    let (mut res, first) = run();
+    if first >= config.max_time {
+        return (res, Stats::from_samples(&[first]));
+    }
+    for _ in 1..config.warmup {
+        res = run().0;
+        if start.elapsed() >= config.max_time {
+            break;
+        }
+    }
+
+    let start = Instant::now();
+    while samples.len() < config.runs.max(1) {
+        let (last, elapsed) = run();
+        res = last;
+        samples.push(elapsed);
+        if start.elapsed() >= config.max_time {
+            break;
+        }
+    }
+    (res, Stats::from_samples(&samples))
+}
+
+/// Times parsing and every solved part of a solution separately.
+pub fn bench<S: Solution>(input: &str, config: &Config) -> Timings {
+    let (parsed, parse) = measure(config, || S::parse(black_box(input)));
+    let parts = Part::ALL
+        .into_iter()
+        .filter_map(|part| {
+            let (answer, stats) = measure(config, || match part {
+                Part::One => S::part1(&parsed),
+                Part::Two => S::part2(&parsed),
+            });
+            answer.is_solved().then_some((part, stats))
+        })
+        .collect();
+    Timings { parse, parts }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Answer;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(6), ms(4)]);
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.max, ms(6));
+        assert_eq!(stats.mean, ms(4));
+        assert_eq!(stats.median, ms(4));
+        assert_eq!(stats.stddev.as_micros(), 1414);
+
+        let stats = Stats::from_samples(&[ms(3), ms(1), ms(2)]);
+        assert_eq!(stats.median, ms(2));
+        assert_eq!(stats.mean, ms(2));
+    }
+
+    #[test]
+    fn measure_runs() {
+        let mut calls = 0;
+        let config = Config {
+            warmup: 2,
+            runs: 5,
+            max_time: Duration::from_secs(10),
+        };
+        let (res, stats) = measure(&config, || {
+            calls += 1;
+            calls
+        });
+        assert_eq!(calls, 7);
+        assert_eq!(res, 7);
+        assert_eq!(stats.runs, 5);
+    }
+
+    #[test]
+    fn measure_slow_run() {
+        let mut calls = 0;
+        let config = Config {
+            warmup: 2,
+            runs: 5,
+            max_time: Duration::ZERO,
+        };
+        let (_, stats) = measure(&config, || calls += 1);
+        assert_eq!(calls, 1);
+        assert_eq!(stats.runs, 1);
+    }
+
+    struct Count;
+
+    impl Solution for Count {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Parsed<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input.lines().collect()
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+            parsed.len().into()
+        }
+    }
+
+    #[test]
+    fn bench_skips_unsolved_parts() {
+        let config = Config {
+            warmup: 1,
+            runs: 3,
+            max_time: Duration::from_secs(10),
+        };
+        let timings = bench::<Count>("a\nb", &config);
+        assert_eq!(timings.parse.runs, 3);
+        assert_eq!(timings.parts.len(), 1);
+        assert_eq!(timings.parts[0].0, Part::One);
+    }
+
+    /// Counts how often its input was parsed.
+    struct Parses;
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    impl Solution for Parses {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 2;
+
+        type Parsed<'a> = &'a str;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            PARSES.fetch_add(1, Ordering::Relaxed);
+            input
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+            parsed.len().into()
+        }
+
+        fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+            parsed.lines().count().into()
+        }
+    }
+
+    #[test]
+    fn bench_parses_only_in_parse_stage() {
+        let config = Config {
+            warmup: 2,
+            runs: 3,
+            max_time: Duration::from_secs(10),
+        };
+        let timings = bench::<Parses>("a\nb", &config);
+        assert_eq!(timings.parts.len(), 2);
+        assert_eq!(PARSES.load(Ordering::Relaxed), 5);
+    }
+}
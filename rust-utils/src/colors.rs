@@ -1,3 +1,10 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+/// Environment variable that turns colours off when set to a non-empty value,
+/// see <https://no-color.org>.
+pub const NO_COLOR_VAR: &str = "NO_COLOR";
+
 pub const RESET: &str = "\x1B[0m";
 pub const BOLD: &str = "\x1B[1m";
 
@@ -28,3 +35,10 @@ pub const BOLD_BRIGHT_YELLOW: &str = "\x1B[1;93m";
 pub const BOLD_BRIGHT_BLUE: &str = "\x1B[1;94m";
 pub const BOLD_BRIGHT_MAGENTA: &str = "\x1B[1;95m";
 pub const BOLD_BRIGHT_CYAN: &str = "\x1B[1;96m";
+
+/// Whether colours should be written to stdout: only if it is a terminal and
+/// `NO_COLOR` is not set.
+pub fn enabled() -> bool {
+    let no_color = env::var_os(NO_COLOR_VAR).is_some_and(|value| !value.is_empty());
+    !no_color && io::stdout().is_terminal()
+}
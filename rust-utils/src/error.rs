@@ -0,0 +1,198 @@
+use crate::input::ResolveError;
+use std::{error, fmt, io, path::PathBuf};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Unwraps results in solutions, where an error is a bug, e.g. input that
+/// does not parse. Panics with the error's message rather than its `Debug`
+/// form, like `"Expected 4 numbers, found 3"`.
+pub trait OrPanic<T> {
+    fn or_panic(self) -> T;
+}
+
+impl<T> OrPanic<T> for Result<T> {
+    #[track_caller]
+    fn or_panic(self) -> T {
+        self.unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    /// An input file was not found in any of the searched places.
+    NotFound(ResolveError),
+    /// A file could not be read or written.
+    Io { path: PathBuf, source: io::Error },
+    /// A line of a grid input differs in length from the first one.
+    NotRectangular {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character of a grid input does not map to a tile.
+    InvalidChar {
+        line: usize,
+        col: usize,
+        found: char,
+    },
+    /// A number in the input could not be parsed, e.g. because it does not
+    /// fit the type.
+    InvalidNumber { text: String, message: String },
+    /// A line holds a different amount of numbers than expected.
+    NumberCount { expected: usize, found: usize },
+    /// A line does not match the pattern it is parsed with. An empty
+    /// `expected` stands for the end of the line, as does an empty `found`.
+    Mismatch {
+        col: usize,
+        expected: String,
+        found: String,
+    },
+    /// A field of a line parsed with a pattern has an invalid value.
+    InvalidField {
+        col: usize,
+        text: String,
+        message: String,
+    },
+    /// A parser built from [`parse::combinator`](crate::parse::combinator)
+    /// did not find what it `expected`. `found` is the rest of the line.
+    Unexpected {
+        line: usize,
+        col: usize,
+        expected: String,
+        found: String,
+    },
+    /// A section of an input made of blocks could not be parsed. `section`
+    /// starts at 1. The message of `error` is part of this one, so it is not
+    /// reported as the source as well.
+    Section { section: usize, error: Box<Error> },
+    /// An input has a different number of sections than expected.
+    SectionCount { expected: usize, found: usize },
+    /// An image is wider or taller than its file format allows.
+    ImageTooLarge {
+        width: usize,
+        height: usize,
+        max: usize,
+    },
+    /// A download needs a session token, but none was given.
+    MissingSession,
+    /// A request failed or was answered with an error status.
+    Http { url: String, message: String },
+    /// An answer contains a tab or line break, which cannot be submitted or
+    /// logged.
+    InvalidAnswer(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound(err) => write!(f, "{}", err),
+            Error::Io { path, source } => {
+                write!(f, "Failed to access file {}: {}", path.display(), source)
+            }
+            Error::NotRectangular {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {} has {} columns, expected {}",
+                line, found, expected
+            ),
+            Error::InvalidChar { line, col, found } => write!(
+                f,
+                "Invalid character {:?} at line {}, column {}",
+                found, line, col
+            ),
+            Error::InvalidNumber { text, message } => {
+                write!(f, "Invalid number {:?}: {}", text, message)
+            }
+            Error::NumberCount { expected, found } => {
+                write!(f, "Expected {} numbers, found {}", expected, found)
+            }
+            Error::Mismatch {
+                col,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Expected {} at column {}, found {}",
+                describe(expected),
+                col,
+                describe(found)
+            ),
+            Error::InvalidField { col, text, message } => {
+                write!(f, "Invalid value {:?} at column {}: {}", text, col, message)
+            }
+            Error::Unexpected {
+                line,
+                col,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Expected {} at line {}, column {}, found {}",
+                expected,
+                line,
+                col,
+                describe(found)
+            ),
+            Error::Section { section, error } => write!(f, "Section {}: {}", section, error),
+            Error::SectionCount { expected, found } => {
+                write!(f, "Expected {} sections, found {}", expected, found)
+            }
+            Error::ImageTooLarge { width, height, max } => write!(
+                f,
+                "Image of {}x{} pixels is too large, the format allows at most {} per side",
+                width, height, max
+            ),
+            Error::MissingSession => write!(
+                f,
+                "No session token found, set {} to the session cookie of adventofcode.com",
+                crate::fetch::SESSION_VAR
+            ),
+            Error::Http { url, message } => write!(f, "Request to {} failed: {}", url, message),
+            Error::InvalidAnswer(answer) => write!(
+                f,
+                "Answer {:?} contains a tab or line break, it cannot be submitted",
+                answer
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::NotFound(err) => Some(err),
+            Error::Io { source, .. } => Some(source),
+            Error::NotRectangular { .. }
+            | Error::InvalidChar { .. }
+            | Error::InvalidNumber { .. }
+            | Error::NumberCount { .. }
+            | Error::Mismatch { .. }
+            | Error::InvalidField { .. }
+            | Error::Section { .. }
+            | Error::SectionCount { .. }
+            | Error::Unexpected { .. }
+            | Error::ImageTooLarge { .. }
+            | Error::MissingSession
+            | Error::Http { .. }
+            | Error::InvalidAnswer(_) => None,
+        }
+    }
+}
+
+/// Quotes a part of a line, or names the end of the line if it is empty.
+fn describe(text: &str) -> String {
+    if text.is_empty() {
+        "end of line".to_string()
+    } else {
+        format!("{:?}", text)
+    }
+}
+
+impl From<ResolveError> for Error {
+    fn from(err: ResolveError) -> Self {
+        Error::NotFound(err)
+    }
+}
@@ -0,0 +1,367 @@
+use crate::{input, Error, Result};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Environment variable holding the value of the `session` cookie of
+/// adventofcode.com.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable pointing to the directory the downloads are cached in.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// Environment variable with a server to talk to instead of adventofcode.com,
+/// e.g. a local mock server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Response of an [`HttpClient`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn ok(body: impl Into<String>) -> Self {
+        Self {
+            status: 200,
+            body: body.into(),
+        }
+    }
+}
+
+/// The HTTP backend of the [`Fetcher`], so that tests do not talk to the real
+/// site. Status codes other than 200 are returned as responses, only
+/// transport failures are errors.
+pub trait HttpClient {
+    fn get(&self, url: &str, session: &str) -> Result<Response>;
+
+    /// Posts a URL encoded form.
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response>;
+}
+
+/// A request received by the [`FakeClient`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Request {
+    pub url: String,
+    /// The posted form, `None` for GET requests.
+    pub form: Option<Vec<(String, String)>>,
+}
+
+/// In-memory [`HttpClient`] for tests, which answers with canned responses
+/// and records every request.
+///
+/// Several responses for the same URL are returned in order, the last one
+/// repeatedly. Unknown URLs are answered with 404.
+#[derive(Debug, Default)]
+pub struct FakeClient {
+    responses: RefCell<HashMap<String, VecDeque<Response>>>,
+    requests: RefCell<Vec<Request>>,
+}
+
+impl FakeClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_response(self, url: impl Into<String>, response: Response) -> Self {
+        self.responses
+            .borrow_mut()
+            .entry(url.into())
+            .or_default()
+            .push_back(response);
+        self
+    }
+
+    /// Returns all requests so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.borrow().clone()
+    }
+
+    /// Returns the URLs of all requests so far.
+    pub fn urls(&self) -> Vec<String> {
+        self.requests
+            .borrow()
+            .iter()
+            .map(|request| request.url.clone())
+            .collect()
+    }
+
+    fn respond(&self, request: Request) -> Result<Response> {
+        let mut responses = self.responses.borrow_mut();
+        let response = match responses.get_mut(&request.url) {
+            Some(queue) if queue.len() > 1 => queue.pop_front(),
+            Some(queue) => queue.front().cloned(),
+            None => None,
+        };
+        self.requests.borrow_mut().push(request);
+        Ok(response.unwrap_or(Response {
+            status: 404,
+            body: "Not Found".to_string(),
+        }))
+    }
+}
+
+impl HttpClient for FakeClient {
+    fn get(&self, url: &str, _session: &str) -> Result<Response> {
+        self.respond(Request {
+            url: url.to_string(),
+            form: None,
+        })
+    }
+
+    fn post(&self, url: &str, _session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        self.respond(Request {
+            url: url.to_string(),
+            form: Some(
+                form.iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+            ),
+        })
+    }
+}
+
+impl<C: HttpClient + ?Sized> HttpClient for &C {
+    fn get(&self, url: &str, session: &str) -> Result<Response> {
+        (**self).get(url, session)
+    }
+
+    fn post(&self, url: &str, session: &str, form: &[(&str, &str)]) -> Result<Response> {
+        (**self).post(url, session, form)
+    }
+}
+
+/// Downloads puzzle inputs and caches them as
+/// `<cache dir>/<year>/dayNN/input.txt`, which mirrors the day crates so that
+/// `<cache dir>/<year>` can be used as an input directory.
+///
+/// Cached inputs are never fetched again.
+pub struct Fetcher<C> {
+    client: C,
+    cache_dir: PathBuf,
+    session: Option<String>,
+    base_url: String,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn new(client: C, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            client,
+            cache_dir: cache_dir.into(),
+            session: None,
+            base_url: BASE_URL.to_string(),
+        }
+    }
+
+    /// Creates a fetcher with the session from `AOC_SESSION`, the server from
+    /// `AOC_BASE_URL` if set and the cache directory from
+    /// [`cache_dir_from_env`].
+    pub fn from_env(client: C) -> Self {
+        let mut fetcher = Self::new(client, cache_dir_from_env());
+        if let Ok(session) = env::var(SESSION_VAR) {
+            fetcher = fetcher.with_session(session);
+        }
+        if let Ok(base_url) = env::var(BASE_URL_VAR) {
+            fetcher = fetcher.with_base_url(base_url);
+        }
+        fetcher
+    }
+
+    pub fn with_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into().trim().to_string());
+        self
+    }
+
+    /// Talks to the given server instead of adventofcode.com.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    pub fn client(&self) -> &C {
+        &self.client
+    }
+
+    /// Directory of the cached files of a day.
+    pub fn day_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}", day))
+    }
+
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("input.txt")
+    }
+
+    pub fn is_cached(&self, year: u16, day: u8) -> bool {
+        self.input_path(year, day).is_file()
+    }
+
+    pub fn puzzle_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("puzzle.html")
+    }
+
+    /// Returns the normalized input of a day, downloading it first if it is
+    /// not cached yet.
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        self.cached(self.input_path(year, day), &url)
+            .map(|input| input::normalize(&input))
+    }
+
+    /// Returns the description page of a day, downloading it first if it is
+    /// not cached yet. Remove the cached page to see a newly unlocked part.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{}/day/{}", self.base_url, year, day);
+        self.cached(self.puzzle_path(year, day), &url)
+    }
+
+    fn cached(&self, path: PathBuf, url: &str) -> Result<String> {
+        if !path.is_file() {
+            write_atomic(&path, &self.get(url)?)?;
+        }
+        fs::read_to_string(&path).map_err(|source| Error::Io { path, source })
+    }
+
+    /// Downloads a page with the session cookie and fails on any status but
+    /// 200.
+    pub fn get(&self, url: &str) -> Result<String> {
+        let session = self.session()?;
+        check(url, self.client.get(url, session)?)
+    }
+
+    /// Posts a form with the session cookie and fails on any status but 200.
+    pub fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String> {
+        let session = self.session()?;
+        check(url, self.client.post(url, session, form)?)
+    }
+
+    pub(crate) fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.session.as_deref().ok_or(Error::MissingSession)
+    }
+}
+
+fn check(url: &str, response: Response) -> Result<String> {
+    if response.status != 200 {
+        return Err(Error::Http {
+            url: url.to_string(),
+            message: format!("status {}: {}", response.status, response.body.trim()),
+        });
+    }
+    Ok(response.body)
+}
+
+/// Returns `AOC_CACHE_DIR`, or `aoc` inside the user's cache directory.
+pub fn cache_dir_from_env() -> PathBuf {
+    if let Some(dir) = env::var_os(CACHE_DIR_VAR) {
+        return PathBuf::from(dir);
+    }
+    match (env::var_os("XDG_CACHE_HOME"), env::var_os("HOME")) {
+        (Some(cache), _) => PathBuf::from(cache).join("aoc"),
+        (None, Some(home)) => PathBuf::from(home).join(".cache").join("aoc"),
+        (None, None) => env::temp_dir().join("aoc"),
+    }
+}
+
+/// Writes through a temporary file, so that an interrupted download never
+/// leaves a partial file in the cache.
+pub(crate) fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let io_error = |source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, contents).map_err(io_error)?;
+    fs::rename(&tmp, path).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URL: &str = "https://adventofcode.com/2024/day/1/input";
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("utils-fetch-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn input_is_fetched_once() {
+        let client = FakeClient::new().with_response(URL, Response::ok("3   4\n4   3\n"));
+        let fetcher = Fetcher::new(&client, cache_dir("once")).with_session("abc");
+
+        assert!(!fetcher.is_cached(2024, 1));
+        assert_eq!(fetcher.input(2024, 1).unwrap(), "3   4\n4   3");
+        assert_eq!(fetcher.input(2024, 1).unwrap(), "3   4\n4   3");
+        assert_eq!(client.urls(), vec![URL]);
+        assert!(fetcher
+            .input_path(2024, 1)
+            .ends_with("2024/day01/input.txt"));
+    }
+
+    #[test]
+    fn cached_input_without_session() {
+        let fetcher = Fetcher::new(FakeClient::new(), cache_dir("cached"));
+        write_atomic(&fetcher.input_path(2024, 2), "7 6 4 2 1\n").unwrap();
+
+        assert_eq!(fetcher.input(2024, 2).unwrap(), "7 6 4 2 1");
+        assert!(fetcher.client().requests().is_empty());
+    }
+
+    #[test]
+    fn missing_session() {
+        let fetcher = Fetcher::new(FakeClient::new(), cache_dir("session"));
+        assert!(matches!(fetcher.input(2024, 3), Err(Error::MissingSession)));
+    }
+
+    #[test]
+    fn error_status_is_not_cached() {
+        let client = FakeClient::new().with_response(
+            "http://localhost/2024/day/25/input",
+            Response {
+                status: 400,
+                body: "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+                    .to_string(),
+            },
+        );
+        let fetcher = Fetcher::new(client, cache_dir("status"))
+            .with_session("expired")
+            .with_base_url("http://localhost/");
+
+        let err = fetcher.input(2024, 25).unwrap_err();
+        assert!(err.to_string().starts_with(
+            "Request to http://localhost/2024/day/25/input failed: status 400: Puzzle inputs differ"
+        ));
+        assert!(!fetcher.is_cached(2024, 25));
+    }
+
+    #[test]
+    fn puzzle_is_fetched_once() {
+        let url = "https://adventofcode.com/2024/day/4";
+        let client = FakeClient::new().with_response(url, Response::ok("<main></main>\n"));
+        let fetcher = Fetcher::new(&client, cache_dir("puzzle")).with_session("abc");
+
+        assert_eq!(fetcher.puzzle(2024, 4).unwrap(), "<main></main>\n");
+        assert_eq!(fetcher.puzzle(2024, 4).unwrap(), "<main></main>\n");
+        assert_eq!(client.urls(), vec![url]);
+        assert!(!fetcher.is_cached(2024, 4));
+    }
+}
@@ -1,42 +1,255 @@
-// #[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
-// enum Direction {
-//     Up,
-//     Down,
-//     Right,
-//     Left,
-// }
-
-// impl Direction {
-//     fn clockwise(&self) -> Self {
-//         match self {
-//             Direction::Up => Direction::Right,
-//             Direction::Right => Direction::Down,
-//             Direction::Down => Direction::Left,
-//             Direction::Left => Direction::Up,
-//         }
-//     }
-
-//     fn counter_clockwise(&self) -> Self {
-//         match self {
-//             Direction::Up => Direction::Left,
-//             Direction::Left => Direction::Down,
-//             Direction::Down => Direction::Right,
-//             Direction::Right => Direction::Up,
-//         }
-//     }
-// }
-
-// impl std::fmt::Display for Direction {
-//     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-//         write!(
-//             f,
-//             "{}",
-//             match self {
-//                 Direction::Up => '^',
-//                 Direction::Down => 'v',
-//                 Direction::Right => '>',
-//                 Direction::Left => '<',
-//             }
-//         )
-//     }
-// }
+use std::fmt;
+
+/// One of the four directions on a grid, in clockwise order starting at the
+/// top.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// Returns an iterator over all directions in clockwise order.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    pub fn clockwise(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn counter_clockwise(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Self::Up | Self::Down)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+
+    /// The change of (row, col) when moving one step into this direction.
+    pub fn offset(self) -> (isize, isize) {
+        Direction8::from(self).offset()
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+}
+
+impl TryFrom<char> for Direction4 {
+    type Error = char;
+
+    /// Parses the arrows `^>v<`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '^' => Ok(Self::Up),
+            '>' => Ok(Self::Right),
+            'v' => Ok(Self::Down),
+            '<' => Ok(Self::Left),
+            _ => Err(c),
+        }
+    }
+}
+
+impl fmt::Display for Direction4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// One of the eight directions on a grid including the diagonals, in
+/// clockwise order starting at the top.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// Returns an iterator over all directions in clockwise order.
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::ALL.into_iter()
+    }
+
+    /// Rotates by 45 degrees.
+    pub fn clockwise(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Rotates by 45 degrees.
+    pub fn counter_clockwise(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// The change of (row, col) when moving one step into this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (-1, 0),
+            Self::UpRight => (-1, 1),
+            Self::Right => (0, 1),
+            Self::DownRight => (1, 1),
+            Self::Down => (1, 0),
+            Self::DownLeft => (1, -1),
+            Self::Left => (0, -1),
+            Self::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '↑',
+            Self::UpRight => '↗',
+            Self::Right => '→',
+            Self::DownRight => '↘',
+            Self::Down => '↓',
+            Self::DownLeft => '↙',
+            Self::Left => '←',
+            Self::UpLeft => '↖',
+        }
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(dir: Direction4) -> Self {
+        match dir {
+            Direction4::Up => Self::Up,
+            Direction4::Right => Self::Right,
+            Direction4::Down => Self::Down,
+            Direction4::Left => Self::Left,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction4 {
+    type Error = Direction8;
+
+    fn try_from(dir: Direction8) -> Result<Self, Self::Error> {
+        match dir {
+            Direction8::Up => Ok(Self::Up),
+            Direction8::Right => Ok(Self::Right),
+            Direction8::Down => Ok(Self::Down),
+            Direction8::Left => Ok(Self::Left),
+            _ => Err(dir),
+        }
+    }
+}
+
+impl TryFrom<char> for Direction8 {
+    type Error = char;
+
+    /// Parses the arrows `↑↗→↘↓↙←↖` as well as `^>v<`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if let Ok(dir) = Direction4::try_from(c) {
+            return Ok(dir.into());
+        }
+        Self::iter().find(|dir| dir.arrow() == c).ok_or(c)
+    }
+}
+
+impl fmt::Display for Direction8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotate4() {
+        assert_eq!(Direction4::Up.clockwise(), Direction4::Right);
+        assert_eq!(Direction4::Left.clockwise(), Direction4::Up);
+        assert_eq!(Direction4::Up.counter_clockwise(), Direction4::Left);
+        assert_eq!(Direction4::Right.opposite(), Direction4::Left);
+        for dir in Direction4::iter() {
+            assert_eq!(dir.clockwise().counter_clockwise(), dir);
+            assert_eq!(dir.clockwise().clockwise(), dir.opposite());
+        }
+    }
+
+    #[test]
+    fn rotate8() {
+        assert_eq!(Direction8::Up.clockwise(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.counter_clockwise(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+        assert_eq!(
+            Direction8::iter().filter(|dir| dir.is_diagonal()).count(),
+            4
+        );
+    }
+
+    #[test]
+    fn arrows() {
+        let dirs: Vec<Direction4> = "^>v<".chars().map(|c| c.try_into().unwrap()).collect();
+        assert_eq!(dirs, Direction4::ALL);
+        assert_eq!(Direction4::try_from('x'), Err('x'));
+        assert_eq!(
+            Direction4::iter()
+                .map(|dir| dir.to_string())
+                .collect::<String>(),
+            "^>v<"
+        );
+        assert_eq!(Direction8::try_from('v'), Ok(Direction8::Down));
+        assert_eq!(Direction8::try_from('↙'), Ok(Direction8::DownLeft));
+        assert_eq!(Direction8::DownLeft.to_string(), "↙");
+    }
+
+    #[test]
+    fn offsets() {
+        assert_eq!(Direction4::Up.offset(), (-1, 0));
+        assert_eq!(Direction4::Left.offset(), (0, -1));
+        assert_eq!(Direction8::DownRight.offset(), (1, 1));
+        assert_eq!(
+            Direction4::try_from(Direction8::DownRight),
+            Err(Direction8::DownRight)
+        );
+        for dir in Direction8::iter() {
+            let (row, col) = dir.offset();
+            let (opposite_row, opposite_col) = dir.opposite().offset();
+            assert_eq!((row + opposite_row, col + opposite_col), (0, 0));
+        }
+    }
+}
@@ -1,26 +1,137 @@
 use crate::grid::position::Position;
+use crate::{Error, Result};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Index, IndexMut};
 
+/// A rectangular grid, stored row by row in a single vector.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Grid<T> {
-    pub width: usize,
-    pub height: usize,
-    data: Vec<Vec<T>>,
+    width: usize,
+    height: usize,
+    data: Vec<T>,
 }
 
 impl<T> Grid<T> {
+    /// Creates a grid from its rows. An empty vector gives an empty grid.
+    ///
+    /// # Panics
+    ///
+    /// If the rows have different lengths.
     pub fn new(vec2d: Vec<Vec<T>>) -> Self {
+        let width = vec2d.first().map_or(0, Vec::len);
+        let height = vec2d.len();
+        let data: Vec<T> = vec2d
+            .into_iter()
+            .enumerate()
+            .flat_map(|(row, values)| {
+                assert_eq!(values.len(), width, "Row {} has a different length", row);
+                values
+            })
+            .collect();
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Creates a grid with the value of every position computed by `f`.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Position) -> T) -> Self {
+        let data = (0..height)
+            .flat_map(|row| (0..width).map(move |col| Position::new(row, col)))
+            .map(&mut f)
+            .collect();
         Self {
-            width: vec2d.first().expect("Empty vector").len(),
-            height: vec2d.len(),
-            data: vec2d,
+            width,
+            height,
+            data,
         }
     }
 
+    /// Parses a grid with one value per character, computed by `f`.
+    ///
+    /// Returns [`Error::InvalidChar`] where `f` returns `None`, and
+    /// [`Error::NotRectangular`] if the lines differ in length.
+    pub fn parse_with(input: &str, f: impl FnMut(Position, char) -> Option<T>) -> Result<Self> {
+        Self::parse_with_markers(input, "", f).map(|(grid, _)| grid)
+    }
+
+    /// Like [`Grid::parse_with`], but also records where the characters of
+    /// `markers` are, e.g. "SE" for start and end. `f` is called for marker
+    /// characters as well and decides what is below them.
+    pub fn parse_with_markers(
+        input: &str,
+        markers: &str,
+        mut f: impl FnMut(Position, char) -> Option<T>,
+    ) -> Result<(Self, Markers)> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let mut height = 0;
+        let mut data = Vec::with_capacity(input.len());
+        let mut found = Markers::default();
+
+        for (row, line) in input.lines().enumerate() {
+            let mut cols = 0;
+            for (col, c) in line.chars().enumerate() {
+                let pos = Position::new(row, col);
+                if markers.contains(c) {
+                    found.positions.entry(c).or_default().push(pos);
+                }
+                data.push(f(pos, c).ok_or(Error::InvalidChar {
+                    line: row + 1,
+                    col: col + 1,
+                    found: c,
+                })?);
+                cols += 1;
+            }
+            if cols != width {
+                return Err(Error::NotRectangular {
+                    line: row + 1,
+                    expected: width,
+                    found: cols,
+                });
+            }
+            height += 1;
+        }
+        let grid = Self {
+            width,
+            height,
+            data,
+        };
+        Ok((grid, found))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn contains(&self, pos: &Position) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    fn index_of(&self, pos: &Position) -> Option<usize> {
+        self.contains(pos).then(|| pos.row * self.width + pos.col)
+    }
+
+    fn position_of(&self, index: usize) -> Position {
+        Position::new(index / self.width, index % self.width)
+    }
+
     pub fn get(&self, pos: &Position) -> Option<&T> {
-        Some(self.data.get(pos.row)?.get(pos.col)?)
+        self.data.get(self.index_of(pos)?)
     }
 
     pub fn get_mut(&mut self, pos: &Position) -> Option<&mut T> {
-        Some(self.data.get_mut(pos.row)?.get_mut(pos.col)?)
+        let index = self.index_of(pos)?;
+        self.data.get_mut(index)
     }
 
     pub fn put(&mut self, pos: &Position, to_put: T) -> bool {
@@ -32,17 +143,66 @@ impl<T> Grid<T> {
         }
     }
 
+    /// Returns a row as a slice.
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "Row {} is out of bounds", row);
+        &self.data[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Returns a row as a mutable slice.
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.height, "Row {} is out of bounds", row);
+        &mut self.data[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Returns an iterator over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Returns an iterator over the values of a column from top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If the column is out of bounds.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(col < self.width, "Column {} is out of bounds", col);
+        self.data[col..].iter().step_by(self.width)
+    }
+
+    /// Returns an iterator over the columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Returns a view of the `width` x `height` rectangle with its top left
+    /// corner at `origin`, or `None` if it does not fit into the grid.
+    pub fn view(&self, origin: Position, width: usize, height: usize) -> Option<View<'_, T>> {
+        (origin.row + height <= self.height && origin.col + width <= self.width).then_some(View {
+            grid: self,
+            origin,
+            width,
+            height,
+        })
+    }
+
     /// Returns an iterator yielding tuples (position, reference to value) for
     /// each element in the grid.
     ///
     /// Iteration proceeds row by row, column by column within each row.
     pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> + '_ {
-        self.data.iter().enumerate().flat_map(|(row, row_vec)| {
-            row_vec
-                .iter()
-                .enumerate()
-                .map(move |(col, value)| (Position::new(row, col), value))
-        })
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (self.position_of(index), value))
     }
 
     /// Returns an iterator yielding tuples (position, mutable reference to value)
@@ -50,13 +210,124 @@ impl<T> Grid<T> {
     ///
     /// Iteration proceeds row by row, column by column within each row.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = (Position, &mut T)> + '_ {
-        self.data.iter_mut().enumerate().flat_map(|(row, row_vec)| {
-            row_vec
-                .iter_mut()
-                .enumerate()
-                .map(move |(col, value)| (Position::new(row, col), value))
+        let width = self.width;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(index, value)| (Position::new(index / width, index % width), value))
+    }
+
+    /// Returns the first position of `value` in reading order.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).next()
+    }
+
+    /// Returns all positions of `value` in reading order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, other)| *other == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Creates a grid of the same size with `f` applied to every value.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: TryFrom<char>> Grid<T> {
+    /// Parses a grid of tiles that can be converted from characters.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::parse_with(input, |_, c| T::try_from(c).ok())
+    }
+
+    /// Parses a grid of tiles and records where the characters of `markers`
+    /// are, see [`Grid::parse_with_markers`].
+    pub fn parse_markers(input: &str, markers: &str) -> Result<(Self, Markers)> {
+        Self::parse_with_markers(input, markers, |_, c| T::try_from(c).ok())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with every position set to `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![value; width * height],
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, so that rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |pos| {
+            self[Position::new(pos.col, pos.row)].clone()
+        })
+    }
+
+    /// Rotates the grid clockwise by 90 degrees.
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |pos| {
+            self[Position::new(self.height - 1 - pos.col, pos.row)].clone()
         })
     }
+
+    /// Rotates the grid counter-clockwise by 90 degrees.
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |pos| {
+            self[Position::new(pos.col, self.width - 1 - pos.row)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.width, self.height, |pos| {
+            self[Position::new(pos.row, self.width - 1 - pos.col)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.width, self.height, |pos| {
+            self[Position::new(self.height - 1 - pos.row, pos.col)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// If the position is out of bounds.
+    fn index(&self, pos: Position) -> &T {
+        match self.index_of(&pos) {
+            Some(index) => &self.data[index],
+            None => panic!("Position {:?} is out of bounds", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    /// # Panics
+    ///
+    /// If the position is out of bounds.
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        match self.index_of(&pos) {
+            Some(index) => &mut self.data[index],
+            None => panic!("Position {:?} is out of bounds", pos),
+        }
+    }
 }
 
 impl From<&str> for Grid<char> {
@@ -64,3 +335,258 @@ impl From<&str> for Grid<char> {
         Self::new(value.lines().map(|line| line.chars().collect()).collect())
     }
 }
+
+/// Prints the grid row by row, without separators between the values.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Positions of special characters found while parsing a grid.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Markers {
+    positions: HashMap<char, Vec<Position>>,
+}
+
+impl Markers {
+    /// Returns the first position of a marker in reading order.
+    pub fn get(&self, marker: char) -> Option<Position> {
+        self.all(marker).first().copied()
+    }
+
+    /// Returns all positions of a marker in reading order.
+    pub fn all(&self, marker: char) -> &[Position] {
+        self.positions.get(&marker).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// A rectangular part of a [`Grid`], with positions relative to its top left
+/// corner.
+#[derive(Clone, Copy, Debug)]
+pub struct View<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Position,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, pos: &Position) -> Option<&'a T> {
+        if pos.row < self.height && pos.col < self.width {
+            self.grid.get(&Position::new(
+                self.origin.row + pos.row,
+                self.origin.col + pos.col,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a row of the view as a slice.
+    ///
+    /// # Panics
+    ///
+    /// If the row is out of bounds.
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.height, "Row {} is out of bounds", row);
+        &self.grid.row(self.origin.row + row)[self.origin.col..self.origin.col + self.width]
+    }
+
+    /// Returns an iterator over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Returns an iterator yielding tuples (position in the view, reference to
+    /// value) in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &'a T)> + '_ {
+        self.rows().enumerate().flat_map(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .map(move |(col, value)| (Position::new(row, col), value))
+        })
+    }
+
+    /// Copies the view into a new grid.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |pos| {
+            self.get(&pos).unwrap().clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from("abc\ndef")
+    }
+
+    #[test]
+    fn constructors() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(1, 0)], 'd');
+        assert_eq!(grid.get(&Position::new(0, 3)), None);
+
+        let empty = Grid::<char>::from("");
+        assert!(empty.is_empty());
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+
+        let filled = Grid::filled(2, 3, 0);
+        assert_eq!(filled.to_string(), "00\n00\n00\n");
+        let from_fn = Grid::from_fn(3, 2, |pos| pos.row * 10 + pos.col);
+        assert_eq!(from_fn.row(1), [10, 11, 12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Row 1 has a different length")]
+    fn ragged_rows() {
+        let _ = Grid::from("abc\nde");
+    }
+
+    #[test]
+    fn index_mut() {
+        let mut grid = grid();
+        grid[Position::new(0, 1)] = 'x';
+        assert!(grid.put(&Position::new(1, 2), 'y'));
+        assert!(!grid.put(&Position::new(2, 0), 'z'));
+        assert_eq!(grid.to_string(), "axc\ndey\n");
+        grid.row_mut(0).fill('-');
+        assert_eq!(grid.to_string(), "---\ndey\n");
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn index_out_of_bounds() {
+        let _ = grid()[Position::new(0, 3)];
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = grid();
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            [['a', 'b', 'c'], ['d', 'e', 'f']]
+        );
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.columns()
+                .map(|col| col.collect::<String>())
+                .collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(grid.iter().nth(4), Some((Position::new(1, 1), &'e')));
+    }
+
+    #[test]
+    fn views() {
+        let grid = Grid::from("abcd\nefgh\nijkl");
+        let view = grid.view(Position::new(1, 1), 2, 2).unwrap();
+        assert_eq!(view.row(0), ['f', 'g']);
+        assert_eq!(view.get(&Position::new(1, 1)), Some(&'k'));
+        assert_eq!(view.get(&Position::new(0, 2)), None);
+        assert_eq!(view.to_grid().to_string(), "fg\njk\n");
+        assert!(grid.view(Position::new(1, 3), 2, 1).is_none());
+        assert!(grid.view(Position::new(0, 0), 4, 3).is_some());
+    }
+
+    #[test]
+    fn find() {
+        let grid = Grid::from("S.#\n#.E\n..#");
+        assert_eq!(grid.find(&'S'), Some(Position::new(0, 0)));
+        assert_eq!(grid.find(&'E'), Some(Position::new(1, 2)));
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            [
+                Position::new(0, 2),
+                Position::new(1, 0),
+                Position::new(2, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn transformations() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed\n");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    enum Tile {
+        Wall,
+        Floor,
+    }
+
+    impl TryFrom<char> for Tile {
+        type Error = char;
+
+        fn try_from(c: char) -> std::result::Result<Self, char> {
+            match c {
+                '#' => Ok(Tile::Wall),
+                '.' | 'S' | 'E' => Ok(Tile::Floor),
+                _ => Err(c),
+            }
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let (grid, markers) = Grid::<Tile>::parse_markers("#S.\n#.E\n.S#", "SE").unwrap();
+        assert_eq!(grid[Position::new(0, 1)], Tile::Floor);
+        assert_eq!(grid.find_all(&Tile::Wall).count(), 3);
+        assert_eq!(markers.get('E'), Some(Position::new(1, 2)));
+        assert_eq!(markers.all('S'), [Position::new(0, 1), Position::new(2, 1)]);
+        assert_eq!(markers.get('#'), None);
+
+        let grid =
+            Grid::parse_with("12\n34", |pos, c| Some(c.to_digit(10)? as usize + pos.row)).unwrap();
+        assert_eq!(grid.row(1), [4, 5]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            Grid::<Tile>::parse("#.\n.x").unwrap_err().to_string(),
+            "Invalid character 'x' at line 2, column 2"
+        );
+        assert_eq!(
+            Grid::<Tile>::parse("#.\n.").unwrap_err().to_string(),
+            "Line 2 has 1 columns, expected 2"
+        );
+        assert!(Grid::<Tile>::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn map() {
+        let grid = Grid::from("#.\n.#").map(|c| *c == '#');
+        assert_eq!(grid.find_all(&true).count(), 2);
+        assert_eq!(grid.map(|wall| u8::from(*wall)).to_string(), "10\n01\n");
+    }
+}
@@ -1,5 +1,12 @@
+mod direction;
+#[allow(clippy::module_inception)]
 mod grid;
 mod position;
+mod render;
+mod vec2;
 
-pub use grid::Grid;
+pub use direction::{Direction4, Direction8};
+pub use grid::{Grid, Markers, View};
 pub use position::Position;
+pub use render::GridRenderer;
+pub use vec2::{Offset, Vec2};
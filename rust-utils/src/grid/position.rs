@@ -1,3 +1,5 @@
+use super::{Direction8, Offset};
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Position {
     pub row: usize,
@@ -44,46 +46,49 @@ impl Position {
         .filter(move |pos| max.contains(pos))
     }
 
-    // fn to(&self, direction: Direction) -> Self {
-    //     match direction {
-    //         Direction::Up => Self::new(self.row - 1, self.col),
-    //         Direction::Down => Self::new(self.row + 1, self.col),
-    //         Direction::Right => Self::new(self.row, self.col + 1),
-    //         Direction::Left => Self::new(self.row, self.col - 1),
-    //     }
-    // }
-
-    // fn dir(&self, from: Position) -> Direction {
-    //     let row_diff = if self.row >= from.row {
-    //         self.row - from.row
-    //     } else {
-    //         from.row - self.row
-    //     };
-    //     let col_diff = if self.col >= from.col {
-    //         self.col - from.col
-    //     } else {
-    //         from.col - self.col
-    //     };
-
-    //     if col_diff >= row_diff {
-    //         if self.col >= from.col {
-    //             Direction::Right
-    //         } else {
-    //             Direction::Left
-    //         }
-    //     } else {
-    //         if self.row <= from.row {
-    //             Direction::Up
-    //         } else {
-    //             Direction::Down
-    //         }
-    //     }
-    // }
+    /// Returns the adjacent position in the given direction, or `None` if its
+    /// row or column would be negative.
+    pub fn step(&self, dir: impl Into<Direction8>) -> Option<Self> {
+        self.checked_add(Offset::from(dir.into()))
+    }
+
+    /// Returns the direction of a neighboring position.
+    pub fn direction_to(&self, other: &Self) -> Option<Direction8> {
+        let offset = (
+            other.row as isize - self.row as isize,
+            other.col as isize - self.col as isize,
+        );
+        Direction8::iter().find(|dir| dir.offset() == offset)
+    }
+
+    /// Returns the position moved by `offset`, or `None` if its row or column
+    /// would be negative.
+    pub fn checked_add(&self, offset: Offset) -> Option<Self> {
+        Some(Self::new(
+            self.row.checked_add_signed(offset.y)?,
+            self.col.checked_add_signed(offset.x)?,
+        ))
+    }
+
+    /// Returns the position `times` steps of `offset` away, or `None` if its
+    /// row or column would be negative or the distance overflows.
+    pub fn offset(&self, offset: Offset, times: isize) -> Option<Self> {
+        self.checked_add(offset.checked_mul(times)?)
+    }
+
+    /// Returns the offset from self to `other`.
+    pub fn offset_to(&self, other: &Self) -> Offset {
+        Offset::new(
+            other.col as isize - self.col as isize,
+            other.row as isize - self.row as isize,
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::grid::Direction4;
 
     #[test]
     fn new() {
@@ -101,6 +106,37 @@ mod tests {
         assert!(!bounds.contains(&Position::new(6, 6)));
     }
 
+    #[test]
+    fn step() {
+        let pos = Position::new(0, 2);
+        assert_eq!(pos.step(Direction4::Right), Some(Position::new(0, 3)));
+        assert_eq!(pos.step(Direction4::Up), None);
+        assert_eq!(pos.step(Direction8::DownLeft), Some(Position::new(1, 1)));
+        assert_eq!(Position::new(0, 0).step(Direction8::UpLeft), None);
+    }
+
+    #[test]
+    fn offset() {
+        let pos = Position::new(2, 3);
+        let offset = Offset::new(1, -1);
+        assert_eq!(pos.offset(offset, 2), Some(Position::new(0, 5)));
+        assert_eq!(pos.offset(offset, 3), None);
+        assert_eq!(pos.offset(offset, -3), Some(Position::new(5, 0)));
+        assert_eq!(pos.offset(offset, -4), None);
+        assert_eq!(pos.offset(offset, isize::MAX), None);
+        assert_eq!(Position::new(0, usize::MAX).step(Direction4::Right), None);
+    }
+
+    #[test]
+    fn direction_to() {
+        let pos = Position::new(1, 1);
+        for dir in Direction8::iter() {
+            assert_eq!(pos.direction_to(&pos.step(dir).unwrap()), Some(dir));
+        }
+        assert_eq!(pos.direction_to(&pos), None);
+        assert_eq!(pos.direction_to(&Position::new(3, 1)), None);
+    }
+
     #[test]
     fn neighbors_middle() {
         let pos = Position::new(2, 2);
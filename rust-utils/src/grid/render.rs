@@ -0,0 +1,150 @@
+use super::{Grid, Position};
+use crate::colors;
+use std::collections::HashMap;
+use std::fmt;
+
+/// How a single cell is drawn, if it differs from the value in the grid.
+#[derive(Clone, Debug, Default)]
+struct Style {
+    symbol: Option<String>,
+    color: Option<&'static str>,
+}
+
+/// Draws a [`Grid`] with overlays on top of its values.
+///
+/// Overlays are layered in the order they are added: a later overlay
+/// replaces the colour or symbol an earlier one set for the same cell.
+/// Colours are the escape codes from [`colors`] and are only written if
+/// [`colors::enabled`] says so, unless overridden with
+/// [`GridRenderer::with_color`].
+///
+/// ```
+/// use utils::{colors, grid::{Grid, GridRenderer, Position}};
+///
+/// let grid = Grid::from("#..\n...");
+/// let path = [Position::new(0, 1), Position::new(1, 1)];
+/// let rendered = GridRenderer::new(&grid)
+///     .with_color(false)
+///     .path(path, colors::BOLD_YELLOW)
+///     .marker(Position::new(1, 2), 'E', colors::BOLD_CYAN)
+///     .to_string();
+/// assert_eq!(rendered, "#..\n..E\n");
+/// ```
+pub struct GridRenderer<'a, T> {
+    grid: &'a Grid<T>,
+    styles: HashMap<Position, Style>,
+    color: bool,
+}
+
+impl<'a, T: fmt::Display> GridRenderer<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            styles: HashMap::new(),
+            color: colors::enabled(),
+        }
+    }
+
+    /// Forces colours on or off.
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Highlights the values at the given positions.
+    pub fn path(self, positions: impl IntoIterator<Item = Position>, color: &'static str) -> Self {
+        self.colors(positions.into_iter().map(|pos| (pos, color)))
+    }
+
+    /// Colours the values of single cells.
+    pub fn colors(mut self, colors: impl IntoIterator<Item = (Position, &'static str)>) -> Self {
+        for (pos, color) in colors {
+            self.styles.entry(pos).or_default().color = Some(color);
+        }
+        self
+    }
+
+    /// Draws `symbol` instead of the value at a position.
+    pub fn marker(self, pos: Position, symbol: impl fmt::Display, color: &'static str) -> Self {
+        self.markers([(pos, symbol)], color)
+    }
+
+    /// Draws symbols instead of the values at several positions.
+    pub fn markers<S: fmt::Display>(
+        mut self,
+        markers: impl IntoIterator<Item = (Position, S)>,
+        color: &'static str,
+    ) -> Self {
+        for (pos, symbol) in markers {
+            let style = self.styles.entry(pos).or_default();
+            style.symbol = Some(symbol.to_string());
+            style.color = Some(color);
+        }
+        self
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for GridRenderer<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (pos, value) in self.grid.iter() {
+            let style = self.styles.get(&pos);
+            let color = style.and_then(|style| style.color).filter(|_| self.color);
+            if let Some(color) = color {
+                write!(f, "{}", color)?;
+            }
+            match style.and_then(|style| style.symbol.as_ref()) {
+                Some(symbol) => write!(f, "{}", symbol)?,
+                None => write!(f, "{}", value)?,
+            }
+            if color.is_some() {
+                write!(f, "{}", colors::RESET)?;
+            }
+            if pos.col + 1 == self.grid.width() {
+                writeln!(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain() {
+        let grid = Grid::from("#.\n.#");
+        assert_eq!(
+            GridRenderer::new(&grid).with_color(false).to_string(),
+            grid.to_string()
+        );
+    }
+
+    #[test]
+    fn layers() {
+        let grid = Grid::from("...\n...");
+        let rendered = GridRenderer::new(&grid)
+            .with_color(true)
+            .path([Position::new(0, 0), Position::new(0, 1)], colors::BLUE)
+            .marker(Position::new(0, 1), '>', colors::RED)
+            .colors([(Position::new(1, 2), colors::GREEN)])
+            .to_string();
+        assert_eq!(
+            rendered,
+            "\x1B[34m.\x1B[0m\x1B[31m>\x1B[0m.\n..\x1B[32m.\x1B[0m\n"
+        );
+    }
+
+    #[test]
+    fn markers_without_color() {
+        let grid = Grid::from("....");
+        let rendered = GridRenderer::new(&grid)
+            .with_color(false)
+            .markers(
+                [(Position::new(0, 0), "S"), (Position::new(0, 3), "E")],
+                colors::BOLD_CYAN,
+            )
+            .to_string();
+        assert_eq!(rendered, "S..E\n");
+    }
+}
@@ -0,0 +1,278 @@
+use super::{Direction4, Direction8, Position};
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Euclid, Signed};
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+/// A two-dimensional vector, e.g. a coordinate that can be negative or the
+/// difference of two coordinates.
+///
+/// `x` grows to the right and `y` downwards, so `x` corresponds to the column
+/// and `y` to the row of a [`Position`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// The difference between two positions on a grid.
+pub type Offset = Vec2<isize>;
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + CheckedAdd + CheckedSub + CheckedMul> Vec2<T> {
+    /// Adds `other`, returning `None` on overflow instead of panicking or
+    /// wrapping like `+`.
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(&other.x)?,
+            self.y.checked_add(&other.y)?,
+        ))
+    }
+
+    /// Subtracts `other`, returning `None` on overflow.
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(&other.x)?,
+            self.y.checked_sub(&other.y)?,
+        ))
+    }
+
+    /// Scales by `factor`, returning `None` on overflow.
+    pub fn checked_mul(&self, factor: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(&factor)?,
+            self.y.checked_mul(&factor)?,
+        ))
+    }
+}
+
+impl<T: Copy + Signed + Ord> Vec2<T> {
+    /// Returns the number of orthogonal steps from self to `other`.
+    pub fn manhattan(&self, other: &Self) -> T {
+        let diff = *other - *self;
+        diff.x.abs() + diff.y.abs()
+    }
+
+    /// Returns the number of steps from self to `other` when diagonal steps
+    /// are allowed.
+    pub fn chebyshev(&self, other: &Self) -> T {
+        let diff = *other - *self;
+        diff.x.abs().max(diff.y.abs())
+    }
+}
+
+impl<T: Copy + Euclid + Add<Output = T>> Vec2<T> {
+    /// Wraps self into the rectangle defined by (0, 0) (inclusive) and
+    /// `bounds` (exclusive), as if the grid repeated in all directions.
+    pub fn rem_euclid(&self, bounds: Self) -> Self {
+        Self::new(self.x.rem_euclid(&bounds.x), self.y.rem_euclid(&bounds.y))
+    }
+
+    /// Adds `other` and wraps the result, see [`Vec2::rem_euclid`].
+    pub fn wrapping_add(&self, other: Self, bounds: Self) -> Self {
+        (*self + other).rem_euclid(bounds)
+    }
+}
+
+impl<T: TryFrom<usize>> Vec2<T> {
+    /// Converts a position, returning `None` if it does not fit into `T`.
+    pub fn from_position(pos: Position) -> Option<Self> {
+        Some(Self::new(
+            pos.col.try_into().ok()?,
+            pos.row.try_into().ok()?,
+        ))
+    }
+}
+
+impl<T: TryInto<usize>> Vec2<T> {
+    /// Converts to a position, returning `None` if a coordinate is negative
+    /// or too large.
+    pub fn to_position(self) -> Option<Position> {
+        Some(Position::new(
+            self.y.try_into().ok()?,
+            self.x.try_into().ok()?,
+        ))
+    }
+}
+
+impl From<Direction8> for Offset {
+    fn from(dir: Direction8) -> Self {
+        let (row, col) = dir.offset();
+        Self::new(col, row)
+    }
+}
+
+impl From<Direction4> for Offset {
+    fn from(dir: Direction4) -> Self {
+        Direction8::from(dir).into()
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    /// Converts an `(x, y)` pair.
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vec2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self {
+        Self::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Vec2<T> {
+    type Output = Self;
+
+    fn div(self, divisor: T) -> Self {
+        Self::new(self.x / divisor, self.y / divisor)
+    }
+}
+
+/// Component-wise remainder, see [`Vec2::rem_euclid`] for wrapping negative
+/// coordinates.
+impl<T: Rem<Output = T>> Rem for Vec2<T> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        Self::new(self.x % other.x, self.y % other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Copy + MulAssign> MulAssign<T> for Vec2<T> {
+    fn mul_assign(&mut self, factor: T) {
+        self.x *= factor;
+        self.y *= factor;
+    }
+}
+
+impl<T: Copy + DivAssign> DivAssign<T> for Vec2<T> {
+    fn div_assign(&mut self, divisor: T) {
+        self.x /= divisor;
+        self.y /= divisor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let mut a = Vec2::new(3, -2);
+        let b = Vec2::new(-1, 5);
+        assert_eq!(a + b, Vec2::new(2, 3));
+        assert_eq!(a - b, Vec2::new(4, -7));
+        assert_eq!(-a, Vec2::new(-3, 2));
+        assert_eq!(a * 3, Vec2::new(9, -6));
+        assert_eq!(a / 2, Vec2::new(1, -1));
+        a += b;
+        a *= 2;
+        a -= Vec2::new(1, 1);
+        assert_eq!(a, Vec2::new(3, 5));
+    }
+
+    #[test]
+    fn checked() {
+        let v = Vec2::new(i8::MAX - 1, 2);
+        assert_eq!(v.checked_add(Vec2::new(1, 1)), Some(Vec2::new(i8::MAX, 3)));
+        assert_eq!(v.checked_add(Vec2::new(2, 0)), None);
+        assert_eq!(
+            v.checked_sub(Vec2::new(0, 3)),
+            Some(Vec2::new(i8::MAX - 1, -1))
+        );
+        assert_eq!(Vec2::new(i8::MIN, 0).checked_sub(Vec2::new(1, 0)), None);
+        assert_eq!(v.checked_mul(2), None);
+        assert_eq!(Vec2::new(3, -4).checked_mul(-2), Some(Vec2::new(-6, 8)));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(1i64, 1);
+        let b = Vec2::new(-2, 5);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(b.manhattan(&a), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+    }
+
+    #[test]
+    fn wrapping() {
+        let bounds = Vec2::new(11, 7);
+        assert_eq!(Vec2::new(-1, 7).rem_euclid(bounds), Vec2::new(10, 0));
+        assert_eq!(
+            Vec2::new(2, 4).wrapping_add(Vec2::new(2, -3) * 5, bounds),
+            Vec2::new(1, 3)
+        );
+    }
+
+    #[test]
+    fn positions() {
+        let pos = Position::new(2, 5);
+        let vec = Vec2::<i32>::from_position(pos).unwrap();
+        assert_eq!(vec, Vec2::new(5, 2));
+        assert_eq!(vec.to_position(), Some(pos));
+        assert_eq!(Vec2::new(-1, 0).to_position(), None);
+        assert_eq!(Vec2::<u8>::from_position(Position::new(0, 256)), None);
+
+        assert_eq!(
+            pos.checked_add(Offset::new(-5, 1)),
+            Some(Position::new(3, 0))
+        );
+        assert_eq!(pos.checked_add(Offset::new(0, -3)), None);
+        assert_eq!(pos.offset_to(&Position::new(0, 6)), Offset::new(1, -2));
+        for dir in Direction8::iter() {
+            assert_eq!(pos.checked_add(dir.into()), pos.step(dir));
+        }
+        assert_eq!(Offset::from(Direction4::Left), Offset::new(-1, 0));
+    }
+}
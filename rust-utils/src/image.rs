@@ -0,0 +1,360 @@
+//! Exports grids as images, with the colour of every cell chosen by the
+//! caller.
+//!
+//! PPM is always available. PNG and animated GIF need the `image` feature.
+
+use crate::grid::{Grid, Position};
+use crate::{Error, Result};
+#[cfg(feature = "image")]
+use num_traits::Bounded;
+use std::fs;
+use std::path::Path;
+#[cfg(feature = "image")]
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::PathBuf,
+    time::Duration,
+};
+
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+
+/// Returns a colour for e.g. the n-th region of a map, so that consecutive
+/// indices get well distinguishable colours.
+pub fn distinct_color(index: usize) -> Rgb {
+    // Steps around the colour wheel by the golden angle.
+    let hue = (index as f64 * 0.618_033_988_75).fract() * 6.0;
+    let x = 1.0 - (hue % 2.0 - 1.0).abs();
+    let (r, g, b) = match hue as usize {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+    [r, g, b].map(|c| (c * 220.0) as u8 + 20)
+}
+
+/// An RGB image, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn filled(width: usize, height: usize, color: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![color; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn pixel(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Returns the pixels as `r, g, b` bytes.
+    pub fn to_rgb_bytes(&self) -> Vec<u8> {
+        self.pixels.concat()
+    }
+
+    /// Encodes the image as binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut ppm = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        ppm.extend(self.to_rgb_bytes());
+        ppm
+    }
+
+    pub fn write_ppm(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        fs::write(path, self.to_ppm()).map_err(|source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    #[cfg(feature = "image")]
+    pub fn write_png(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let (width, height) = dimensions::<u32>(self.width, self.height)?;
+        let write = || -> std::result::Result<(), png::EncodingError> {
+            let out = BufWriter::new(File::create(path)?);
+            let mut encoder = png::Encoder::new(out, width, height);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&self.to_rgb_bytes())?;
+            writer.finish()
+        };
+        write().map_err(|err| io_error(path, err))
+    }
+}
+
+impl<T> Grid<T> {
+    /// Draws every cell as a `scale` x `scale` square in the colour returned
+    /// by `color`.
+    pub fn to_image(&self, scale: usize, mut color: impl FnMut(Position, &T) -> Rgb) -> Image {
+        let width = self.width() * scale;
+        let mut image = Image::filled(width, self.height() * scale, BLACK);
+        for (pos, value) in self.iter() {
+            let color = color(pos, value);
+            for y in pos.row * scale..(pos.row + 1) * scale {
+                image.pixels[y * width + pos.col * scale..y * width + (pos.col + 1) * scale]
+                    .fill(color);
+            }
+        }
+        image
+    }
+}
+
+/// Writes frames of the same size into an animated GIF, which loops forever.
+#[cfg(feature = "image")]
+pub struct GifWriter {
+    encoder: gif::Encoder<BufWriter<File>>,
+    path: PathBuf,
+    width: u16,
+    height: u16,
+    delay: u16,
+}
+
+#[cfg(feature = "image")]
+impl GifWriter {
+    /// Creates the file. `delay` is how long each frame is shown, GIF rounds
+    /// it to hundredths of a second.
+    ///
+    /// Returns [`Error::ImageTooLarge`] for frames wider or taller than 65535
+    /// pixels.
+    pub fn create(
+        path: impl AsRef<Path>,
+        width: usize,
+        height: usize,
+        delay: Duration,
+    ) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let (width, height) = dimensions::<u16>(width, height)?;
+        let create = || -> std::result::Result<_, gif::EncodingError> {
+            let out = BufWriter::new(File::create(&path)?);
+            let mut encoder = gif::Encoder::new(out, width, height, &[])?;
+            encoder.set_repeat(gif::Repeat::Infinite)?;
+            Ok(encoder)
+        };
+        let encoder = create().map_err(|err| io_error(&path, err))?;
+        Ok(Self {
+            encoder,
+            path,
+            width,
+            height,
+            delay: (delay.as_millis() / 10).min(u16::MAX as u128) as u16,
+        })
+    }
+
+    /// Appends a frame. Frames with more than 256 colours are quantized.
+    ///
+    /// # Panics
+    ///
+    /// If the frame differs in size from the GIF.
+    pub fn push(&mut self, image: &Image) -> Result<()> {
+        let (width, height) = (self.width, self.height);
+        assert_eq!(
+            (image.width, image.height),
+            (width.into(), height.into()),
+            "Frame size differs from the GIF"
+        );
+        let mut frame = match palette(image) {
+            Some((palette, indices)) => {
+                gif::Frame::from_palette_pixels(width, height, indices, palette, None)
+            }
+            None => gif::Frame::from_rgb_speed(width, height, &image.to_rgb_bytes(), 10),
+        };
+        frame.delay = self.delay;
+        self.encoder
+            .write_frame(&frame)
+            .map_err(|err| io_error(&self.path, err))
+    }
+
+    /// Writes the end of the GIF. Dropping the writer does the same, but
+    /// ignores errors.
+    pub fn finish(self) -> Result<()> {
+        let mut out = self
+            .encoder
+            .into_inner()
+            .map_err(|err| io_error(&self.path, err))?;
+        out.flush().map_err(|err| io_error(&self.path, err))
+    }
+}
+
+/// Writes all frames into an animated GIF, see [`GifWriter`].
+#[cfg(feature = "image")]
+pub fn write_gif(
+    path: impl AsRef<Path>,
+    frames: impl IntoIterator<Item = Image>,
+    delay: Duration,
+) -> Result<()> {
+    let mut frames = frames.into_iter().peekable();
+    let Some(first) = frames.peek() else {
+        return Err(io_error(path.as_ref(), "no frames to write"));
+    };
+    let mut writer = GifWriter::create(path, first.width, first.height, delay)?;
+    for frame in frames {
+        writer.push(&frame)?;
+    }
+    writer.finish()
+}
+
+/// Returns the palette as `r, g, b` bytes and the index of every pixel, if
+/// there are at most 256 colours.
+#[cfg(feature = "image")]
+fn palette(image: &Image) -> Option<(Vec<u8>, Vec<u8>)> {
+    let mut colors: Vec<Rgb> = Vec::new();
+    let mut indices = Vec::with_capacity(image.pixels.len());
+    let mut last: Option<(Rgb, u8)> = None;
+    for &pixel in &image.pixels {
+        let index = match last {
+            Some((color, index)) if color == pixel => index,
+            _ => match colors.iter().position(|&color| color == pixel) {
+                Some(index) => index as u8,
+                None if colors.len() < 256 => {
+                    colors.push(pixel);
+                    (colors.len() - 1) as u8
+                }
+                None => return None,
+            },
+        };
+        last = Some((pixel, index));
+        indices.push(index);
+    }
+    Some((colors.concat(), indices))
+}
+
+/// Converts the size of an image to the integer type of a file format.
+#[cfg(feature = "image")]
+fn dimensions<T>(width: usize, height: usize) -> Result<(T, T)>
+where
+    T: TryFrom<usize> + Bounded + Into<u64>,
+{
+    match (T::try_from(width), T::try_from(height)) {
+        (Ok(width), Ok(height)) => Ok((width, height)),
+        _ => Err(Error::ImageTooLarge {
+            width,
+            height,
+            max: T::max_value().into() as usize,
+        }),
+    }
+}
+
+#[cfg(feature = "image")]
+fn io_error(path: &Path, err: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
+    Error::Io {
+        path: path.to_path_buf(),
+        source: io::Error::other(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::path::PathBuf;
+
+    fn image() -> Image {
+        Grid::from("#.\n.#").to_image(2, |_, c| if *c == '#' { WHITE } else { BLACK })
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("utils-image-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn scaled() {
+        let image = image();
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.pixel(1, 1), Some(WHITE));
+        assert_eq!(image.pixel(2, 1), Some(BLACK));
+        assert_eq!(image.pixel(3, 3), Some(WHITE));
+        assert_eq!(image.pixel(4, 0), None);
+    }
+
+    #[test]
+    fn distinct_colors() {
+        assert_eq!(distinct_color(0), [240, 20, 20]);
+        for i in 0..100 {
+            assert_ne!(distinct_color(i), distinct_color(i + 1));
+        }
+    }
+
+    #[test]
+    fn ppm() {
+        let ppm = Grid::from("ab")
+            .to_image(1, |pos, _| [pos.col as u8, 2, 3])
+            .to_ppm();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\x00\x02\x03\x01\x02\x03");
+
+        let path = temp_path("grid.ppm");
+        image().write_ppm(&path).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), image().to_ppm());
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn png() {
+        let path = temp_path("grid.png");
+        image().write_png(&path).unwrap();
+
+        let decoder = png::Decoder::new(io::BufReader::new(File::open(&path).unwrap()));
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size().unwrap()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (4, 4));
+        assert_eq!(&pixels[..info.buffer_size()], image().to_rgb_bytes());
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn gif() {
+        let path = temp_path("frames.gif");
+        let frames = (0..3).map(|i| Grid::filled(2, 2, i).to_image(1, |_, i| [*i * 100, 0, 0]));
+        write_gif(&path, frames, Duration::from_millis(100)).unwrap();
+
+        let mut decoder = gif::DecodeOptions::new();
+        decoder.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = decoder.read_info(File::open(&path).unwrap()).unwrap();
+        let mut reds = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            assert_eq!(frame.delay, 10);
+            reds.push(frame.buffer[0]);
+        }
+        assert_eq!(reds, [0, 100, 200]);
+
+        assert!(write_gif(&path, [], Duration::ZERO).is_err());
+
+        let err = GifWriter::create(&path, 70_000, 2, Duration::ZERO)
+            .err()
+            .unwrap();
+        assert!(matches!(err, Error::ImageTooLarge { max: 65535, .. }));
+    }
+
+    #[cfg(feature = "image")]
+    #[test]
+    fn many_colors() {
+        let image =
+            Grid::filled(30, 30, ()).to_image(1, |pos, _| [pos.row as u8, pos.col as u8, 0]);
+        assert!(palette(&image).is_none());
+        let path = temp_path("colors.gif");
+        write_gif(&path, [image], Duration::ZERO).unwrap();
+    }
+}
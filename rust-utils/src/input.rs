@@ -1,48 +1,194 @@
+use crate::{Error, Result};
 use std::env;
+use std::error;
+use std::fmt;
 use std::fs;
-use std::io;
-use std::path;
+use std::path::{Path, PathBuf};
+
+pub use crate::parse::sections;
+
+/// Environment variable pointing to a directory that mirrors the crate
+/// directories, e.g. `$AOC_INPUT_DIR/day01/input.txt`, to keep inputs outside
+/// of the repository.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
 
 pub fn read_file(filename: &str) -> String {
-    let dir = current_mod_dir().expect("Failed to get directory of current module");
-    let path = dir.join(filename);
-    fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read file {}", path.display()))
+    try_read_file(filename).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub fn try_read_file(filename: &str) -> Result<String> {
+    InputResolver::from_env().read(filename)
+}
+
+pub fn read_lines(filename: &str) -> Result<Vec<String>> {
+    Ok(try_read_file(filename)?.lines().map(String::from).collect())
+}
+
+/// Reads a file and checks that it is a rectangular grid, see
+/// [`check_rectangular`].
+pub fn try_read_grid(filename: &str) -> Result<String> {
+    let input = try_read_file(filename)?;
+    check_rectangular(&input)?;
+    Ok(input)
+}
+
+/// Reads a file and splits it into its blank-line separated sections, see
+/// [`sections`].
+pub fn read_sections(filename: &str) -> Result<Vec<String>> {
+    Ok(sections(&try_read_file(filename)?)
+        .map(String::from)
+        .collect())
+}
+
+/// Brings the input into the form every day expects, no matter how the file
+/// was checked out or edited: the BOM is stripped, line endings become `\n`
+/// and trailing whitespace is removed from every line and from the end, so
+/// the input does not end with a newline either.
+pub fn normalize(input: &str) -> String {
+    input
+        .strip_prefix('\u{feff}')
+        .unwrap_or(input)
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+/// Checks that all lines have the same length and returns the number of rows
+/// and columns.
+pub fn check_rectangular(input: &str) -> Result<(usize, usize)> {
+    let mut lines = input.lines();
+    let cols = lines.next().map_or(0, |line| line.chars().count());
+    let mut rows = if input.is_empty() { 0 } else { 1 };
+
+    for line in lines {
+        let found = line.chars().count();
+        if found != cols {
+            return Err(Error::NotRectangular {
+                line: rows + 1,
+                expected: cols,
+                found,
+            });
+        }
+        rows += 1;
+    }
+    Ok((rows, cols))
 }
 
-fn current_mod_dir() -> io::Result<path::PathBuf> {
-    let cwd = env::current_dir()?;
-    let exe = env::current_exe()?;
-    let module = exe
-        .file_name()
-        .and_then(|f| f.to_str())
-        .map(|s| {
-            s.chars()
-                .take_while(|c| c.is_alphanumeric())
-                .collect::<String>()
-        })
-        .unwrap_or_default();
-    let mut res = cwd.clone();
-
-    loop {
-        if let Some(component) = res.file_name() {
-            if exe
-                .components()
-                .rev()
-                .skip(1)
-                .any(|d| d.as_os_str() == component)
-            {
-                break;
+/// Finds input files in a fixed order instead of guessing from the location
+/// of the executable:
+///
+/// 1. Absolute paths are used as they are.
+/// 2. The input directory (`AOC_INPUT_DIR`), in the subdirectory named like
+///    the crate directory if that is known.
+/// 3. The crate directory (`CARGO_MANIFEST_DIR`).
+/// 4. The current working directory.
+#[derive(Clone, Debug, Default)]
+pub struct InputResolver {
+    manifest_dir: Option<PathBuf>,
+    input_dir: Option<PathBuf>,
+    current_dir: Option<PathBuf>,
+}
+
+impl InputResolver {
+    /// Creates a resolver that only searches the current working directory.
+    pub fn new() -> Self {
+        Self {
+            current_dir: env::current_dir().ok(),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a resolver from `AOC_INPUT_DIR` and `CARGO_MANIFEST_DIR`, which
+    /// cargo sets for `cargo run` and `cargo test`.
+    pub fn from_env() -> Self {
+        let mut resolver = Self::new();
+        resolver.input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+        resolver.manifest_dir = env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
+        resolver
+    }
+
+    /// Searches the given crate directory instead of `CARGO_MANIFEST_DIR`.
+    pub fn with_manifest_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.manifest_dir = Some(dir.into());
+        self
+    }
+
+    /// Searches the given input directory instead of `AOC_INPUT_DIR`.
+    pub fn with_input_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.input_dir = Some(dir.into());
+        self
+    }
+
+    /// Returns every path that is searched for the file, in order.
+    pub fn candidates(&self, filename: impl AsRef<Path>) -> Vec<PathBuf> {
+        let filename = filename.as_ref();
+        if filename.is_absolute() {
+            return vec![filename.to_path_buf()];
+        }
+        let mut candidates = Vec::new();
+
+        if let Some(input_dir) = &self.input_dir {
+            match self.manifest_dir.as_ref().and_then(|dir| dir.file_name()) {
+                Some(crate_dir) => candidates.push(input_dir.join(crate_dir).join(filename)),
+                None => candidates.push(input_dir.join(filename)),
             }
         }
-        if !res.pop() {
-            res = cwd;
-            break;
+        if let Some(manifest_dir) = &self.manifest_dir {
+            candidates.push(manifest_dir.join(filename));
+        }
+        if let Some(current_dir) = &self.current_dir {
+            candidates.push(current_dir.join(filename));
+        }
+        candidates.dedup();
+        candidates
+    }
+
+    /// Resolves the file, reads it and [`normalize`]s it.
+    pub fn read(&self, filename: impl AsRef<Path>) -> Result<String> {
+        let path = self.resolve(filename)?;
+        fs::read_to_string(&path)
+            .map(|input| normalize(&input))
+            .map_err(|source| Error::Io { path, source })
+    }
+
+    /// Returns the first candidate that is an existing file.
+    pub fn resolve(
+        &self,
+        filename: impl AsRef<Path>,
+    ) -> std::result::Result<PathBuf, ResolveError> {
+        let searched = self.candidates(&filename);
+        match searched.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(ResolveError {
+                filename: filename.as_ref().to_path_buf(),
+                searched,
+            }),
+        }
+    }
+}
+
+/// The file was not found in any of the searched places.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolveError {
+    pub filename: PathBuf,
+    pub searched: Vec<PathBuf>,
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to find {}, searched:", self.filename.display())?;
+        for path in &self.searched {
+            write!(f, "\n  {}", path.display())?;
         }
+        Ok(())
     }
-    res.push(module);
-    Ok(res)
 }
 
+impl error::Error for ResolveError {}
+
 pub fn debug_paths() {
     // 1. CARGO_MANIFEST_DIR
     if let Ok(manifest_dir) = env::var("CARGO_MANIFEST_DIR") {
@@ -90,10 +236,10 @@ pub fn debug_paths() {
         eprintln!("{}", out_dir);
     }
 
-    // 9. Current module directory (custom)
-    if let Ok(mod_dir) = current_mod_dir() {
-        eprintln!("\n=== current_mod_dir() ===");
-        eprintln!("{:?}", mod_dir);
+    // 9. Input search paths
+    eprintln!("\n=== InputResolver::from_env().candidates(\"input.txt\") ===");
+    for candidate in InputResolver::from_env().candidates("input.txt") {
+        eprintln!("{:?}", candidate);
     }
 
     eprintln!();
@@ -113,4 +259,142 @@ mod tests {
     fn print_debug_paths() {
         debug_paths();
     }
+
+    #[test]
+    fn try_read_file_missing() {
+        let err = try_read_file("missing.txt").unwrap_err();
+        assert!(matches!(err, Error::NotFound(_)));
+        assert!(err
+            .to_string()
+            .starts_with("Failed to find missing.txt, searched:"));
+    }
+
+    #[test]
+    fn read_lines_and_sections() {
+        let lines = read_lines("Cargo.toml").unwrap();
+        assert_eq!(lines[0], "[package]");
+        let sections = read_sections("Cargo.toml").unwrap();
+        assert!(sections[0].starts_with("[package]"));
+        assert!(sections[1].starts_with("[dependencies]"));
+    }
+
+    #[test]
+    fn read_io_error() {
+        let dir = temp_dir("io");
+        fs::write(dir.join("input.txt"), [0xff, 0xfe]).unwrap();
+
+        let resolver = InputResolver::default().with_manifest_dir(&dir);
+        match resolver.read("input.txt") {
+            Err(Error::Io { path, .. }) => assert_eq!(path, dir.join("input.txt")),
+            res => panic!("Expected an IO error, got {:?}", res),
+        }
+    }
+
+    #[test]
+    fn normalize_crlf_and_bom() {
+        let input = "\u{feff}Register A: 729\r\n\r\nProgram: 0,1\r\n";
+        assert_eq!(normalize(input), "Register A: 729\n\nProgram: 0,1");
+    }
+
+    #[test]
+    fn normalize_trailing_whitespace() {
+        assert_eq!(normalize("#..# \n \n.##.\t\n\n\n"), "#..#\n\n.##.");
+        assert_eq!(normalize("3   4\n4   3"), "3   4\n4   3");
+        assert_eq!(normalize("\n \n"), "");
+    }
+
+    #[test]
+    fn read_normalizes() {
+        let dir = temp_dir("normalize");
+        fs::write(dir.join("input.txt"), "\u{feff}S.E\r\n...\r\n").unwrap();
+
+        let resolver = InputResolver::default().with_manifest_dir(&dir);
+        assert_eq!(resolver.read("input.txt").unwrap(), "S.E\n...");
+    }
+
+    #[test]
+    fn check_rectangular_grid() {
+        assert_eq!(check_rectangular("S.E\n...").unwrap(), (2, 3));
+        assert_eq!(check_rectangular("").unwrap(), (0, 0));
+
+        let err = check_rectangular("S.E\n..\n...").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::NotRectangular {
+                line: 2,
+                expected: 3,
+                found: 2
+            }
+        ));
+        assert_eq!(err.to_string(), "Line 2 has 2 columns, expected 3");
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("utils-input-{}-{}", std::process::id(), name));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn resolve_manifest_dir() {
+        let manifest_dir = temp_dir("manifest").join("day01");
+        fs::create_dir_all(&manifest_dir).unwrap();
+        fs::write(manifest_dir.join("input.txt"), "1 2").unwrap();
+
+        let resolver = InputResolver::default().with_manifest_dir(&manifest_dir);
+        assert_eq!(
+            resolver.resolve("input.txt"),
+            Ok(manifest_dir.join("input.txt"))
+        );
+    }
+
+    #[test]
+    fn resolve_input_dir_first() {
+        let root = temp_dir("override");
+        let manifest_dir = root.join("repo").join("day02");
+        let input_dir = root.join("inputs");
+        fs::create_dir_all(&manifest_dir).unwrap();
+        fs::create_dir_all(input_dir.join("day02")).unwrap();
+        fs::write(manifest_dir.join("input.txt"), "repo").unwrap();
+        fs::write(input_dir.join("day02").join("input.txt"), "override").unwrap();
+
+        let resolver = InputResolver::default()
+            .with_manifest_dir(&manifest_dir)
+            .with_input_dir(&input_dir);
+        assert_eq!(
+            resolver.resolve("input.txt"),
+            Ok(input_dir.join("day02").join("input.txt"))
+        );
+        assert_eq!(
+            resolver.resolve("example.txt").unwrap_err().searched,
+            vec![
+                input_dir.join("day02").join("example.txt"),
+                manifest_dir.join("example.txt"),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_absolute_path() {
+        let file = temp_dir("absolute").join("custom.txt");
+        fs::write(&file, "custom").unwrap();
+
+        let resolver = InputResolver::default().with_manifest_dir("/nonexistent");
+        assert_eq!(resolver.candidates(&file), vec![file.clone()]);
+        assert_eq!(resolver.resolve(&file), Ok(file));
+    }
+
+    #[test]
+    fn resolve_error_lists_searched() {
+        let resolver = InputResolver::default()
+            .with_manifest_dir("/nonexistent/day03")
+            .with_input_dir("/nonexistent/inputs");
+        let err = resolver.resolve("input.txt").unwrap_err();
+
+        assert_eq!(err.filename, PathBuf::from("input.txt"));
+        assert_eq!(
+            err.to_string(),
+            "Failed to find input.txt, searched:\n  /nonexistent/inputs/day03/input.txt\n  /nonexistent/day03/input.txt"
+        );
+    }
 }
@@ -1,4 +1,18 @@
+mod error;
+
+#[cfg(feature = "animate")]
+pub mod animate;
+pub mod bench;
 pub mod colors;
+pub mod fetch;
 pub mod grid;
+pub mod image;
 pub mod input;
+pub mod memo;
 pub mod parse;
+pub mod puzzle;
+pub mod search;
+pub mod solution;
+pub mod submit;
+
+pub use error::{Error, OrPanic, Result};
@@ -0,0 +1,202 @@
+//! A cache for recursive functions, replacing a `HashMap` threaded through
+//! every call by hand.
+//!
+//! [`Memo::compute`] takes the function as a closure that gets a handle to
+//! call itself through the cache:
+//!
+//! ```
+//! use utils::memo::Memo;
+//!
+//! let mut memo = Memo::new();
+//! let fib = memo.compute(90, |fib, &n: &u64| {
+//!     if n < 2 {
+//!         n
+//!     } else {
+//!         fib(n - 1) + fib(n - 2)
+//!     }
+//! });
+//! assert_eq!(fib, 2880067194370816120);
+//! assert_eq!(memo.stats().misses, 91);
+//! ```
+//!
+//! Keys may borrow from the input, e.g. `Memo<&str, usize>` for suffixes of
+//! a line.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+/// A memoized function, called with a handle to recurse and the key.
+type Body<'f, K, V> = dyn Fn(&mut dyn FnMut(K) -> V, &K) -> V + 'f;
+
+/// Cached results by key, with how often the cache was hit.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    stats: Stats,
+}
+
+/// How many lookups found a cached result and how many had to compute it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cache hits: {}, cache misses: {}",
+            self.hits, self.misses
+        )
+    }
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            cache: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the result for `key`, computing it with `f` if it is not
+    /// cached yet. `f` gets the key and a function to look up other keys
+    /// the same way, which is how it recurses.
+    pub fn compute<F>(&mut self, key: K, f: F) -> V
+    where
+        F: Fn(&mut dyn FnMut(K) -> V, &K) -> V,
+    {
+        self.compute_dyn(key, &f)
+    }
+
+    fn compute_dyn(&mut self, key: K, f: &Body<'_, K, V>) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        let value = f(&mut |key| self.compute_dyn(key, f), &key);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Returns the result for `key`, computing it with `f` if it is not
+    /// cached yet. For functions that do not recurse.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.stats.hits += 1;
+            return value.clone();
+        }
+        self.stats.misses += 1;
+        self.cache.entry(key).or_insert(f()).clone()
+    }
+
+    /// Returns the cached result for `key` without computing it. This does
+    /// not count towards the stats.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.cache.get(key)
+    }
+}
+
+impl<K, V> Memo<K, V> {
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    /// Forgets all cached results and resets the stats, e.g. between parts
+    /// that cache different things under the same keys.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.stats = Stats::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_arrangements<'a>(
+        design: &'a str,
+        towels: &[&str],
+        memo: &mut Memo<&'a str, usize>,
+    ) -> usize {
+        memo.compute(design, |count, &design| {
+            if design.is_empty() {
+                return 1;
+            }
+            towels
+                .iter()
+                .filter_map(|towel| design.strip_prefix(towel))
+                .map(count)
+                .sum()
+        })
+    }
+
+    #[test]
+    fn recursion_with_borrowed_keys() {
+        let towels = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+        let mut memo = Memo::new();
+        assert_eq!(count_arrangements("rrbgbr", &towels, &mut memo), 6);
+        assert_eq!(memo.get("gbr"), Some(&3));
+        assert_eq!(memo.get("x"), None);
+        // Every suffix is computed once.
+        assert_eq!(memo.stats().misses, 7);
+        assert_eq!(memo.len(), 7);
+
+        let hits = memo.stats().hits;
+        assert_eq!(count_arrangements("bgbr", &towels, &mut memo), 3);
+        assert_eq!(memo.stats().hits, hits + 1);
+        assert_eq!(memo.stats().misses, 7);
+    }
+
+    #[test]
+    fn without_recursion() {
+        let mut memo = Memo::new();
+        let mut calls = 0;
+        for word in ["a", "bb", "a", "a"] {
+            let len = memo.get_or_insert_with(word, || {
+                calls += 1;
+                word.len()
+            });
+            assert_eq!(len, word.len());
+        }
+        assert_eq!(calls, 2);
+        assert_eq!(memo.stats(), Stats { hits: 2, misses: 2 });
+        assert_eq!(memo.stats().to_string(), "cache hits: 2, cache misses: 2");
+    }
+
+    #[test]
+    fn clear() {
+        let mut memo = Memo::new();
+        memo.compute(3, |_, &n: &u32| n * 2);
+        memo.compute(3, |_, &n: &u32| n * 3);
+        assert_eq!(memo.get(&3), Some(&6));
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), Stats::default());
+        assert_eq!(memo.compute(3, |_, &n: &u32| n * 3), 9);
+    }
+}
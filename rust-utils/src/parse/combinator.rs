@@ -0,0 +1,404 @@
+//! Small parser combinators for inputs that are more than a pattern per line,
+//! like instructions hidden in noise or lists of lists.
+//!
+//! A parser is anything implementing [`Parser`], which includes every
+//! `FnMut(&str) -> PResult<T>`. It returns the parsed value and the input
+//! that is left, or a [`Failure`] saying what it expected where.
+//!
+//! ```
+//! use utils::parse::combinator::*;
+//!
+//! let mul = delimited(
+//!     tag("mul("),
+//!     separated_pair(integer::<u64>(), tag(","), integer::<u64>()),
+//!     tag(")"),
+//! );
+//! let sum: u64 = find_all(mul, "xmul(2,4)%&mul[3,7]!mul(5,5)")
+//!     .map(|(a, b)| a * b)
+//!     .sum();
+//! assert_eq!(sum, 33);
+//!
+//! let program = preceded(tag("Program: "), separated(integer::<u8>(), tag(",")));
+//! assert_eq!(parse_all(program, "Program: 0,3,5,4").unwrap(), [0, 3, 5, 4]);
+//! ```
+
+use crate::{Error, Result};
+use num_traits::PrimInt;
+use std::{iter, str::FromStr};
+
+/// Where and why a parser failed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Failure<'a> {
+    /// The input left where the parser failed.
+    pub rest: &'a str,
+    /// A description of what would have matched, e.g. `"a number"`.
+    pub expected: String,
+}
+
+pub type PResult<'a, T> = std::result::Result<(T, &'a str), Failure<'a>>;
+
+pub trait Parser<'a, T> {
+    fn parse(&mut self, input: &'a str) -> PResult<'a, T>;
+
+    /// Transforms the parsed value.
+    fn map<U>(mut self, mut f: impl FnMut(T) -> U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| {
+            let (value, rest) = self.parse(input)?;
+            Ok((f(value), rest))
+        }
+    }
+
+    /// Returns `value` instead of the parsed value.
+    fn value<U: Clone>(self, value: U) -> impl Parser<'a, U>
+    where
+        Self: Sized,
+    {
+        self.map(move |_| value.clone())
+    }
+
+    /// Tries `other` where this parser fails.
+    fn or(mut self, mut other: impl Parser<'a, T>) -> impl Parser<'a, T>
+    where
+        Self: Sized,
+    {
+        move |input: &'a str| match self.parse(input) {
+            Ok(ok) => Ok(ok),
+            Err(first) => other.parse(input).map_err(|second| furthest(first, second)),
+        }
+    }
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: FnMut(&'a str) -> PResult<'a, T>,
+{
+    fn parse(&mut self, input: &'a str) -> PResult<'a, T> {
+        self(input)
+    }
+}
+
+fn fail<'a, T>(rest: &'a str, expected: impl Into<String>) -> PResult<'a, T> {
+    Err(Failure {
+        rest,
+        expected: expected.into(),
+    })
+}
+
+/// Keeps the failure that got further into the input, or both expectations
+/// if they failed at the same place.
+fn furthest<'a>(first: Failure<'a>, second: Failure<'a>) -> Failure<'a> {
+    match first.rest.len().cmp(&second.rest.len()) {
+        std::cmp::Ordering::Less => first,
+        std::cmp::Ordering::Greater => second,
+        std::cmp::Ordering::Equal => Failure {
+            rest: first.rest,
+            expected: format!("{} or {}", first.expected, second.expected),
+        },
+    }
+}
+
+/// Matches exactly `tag`.
+pub fn tag<'a>(tag: &'a str) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| match input.strip_prefix(tag) {
+        Some(rest) => Ok((&input[..tag.len()], rest)),
+        None => fail(input, format!("{:?}", tag)),
+    }
+}
+
+/// Matches a decimal number, with a leading `-` if `T` is signed.
+pub fn integer<'a, T>() -> impl Parser<'a, T>
+where
+    T: FromStr + PrimInt,
+{
+    move |input: &'a str| {
+        let sign = usize::from(T::min_value() < T::zero() && input.starts_with('-'));
+        let digits = input[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return fail(input, "a number");
+        }
+        let (text, rest) = input.split_at(sign + digits);
+        match text.parse() {
+            Ok(number) => Ok((number, rest)),
+            Err(_) => fail(input, "a number in range"),
+        }
+    }
+}
+
+/// Matches the longest run of characters satisfying `pred`, which may be
+/// empty.
+pub fn take_while<'a>(mut pred: impl FnMut(char) -> bool) -> impl Parser<'a, &'a str> {
+    move |input: &'a str| {
+        let end = input.find(|c| !pred(c)).unwrap_or(input.len());
+        Ok(input.split_at(end))
+    }
+}
+
+/// Like [`take_while`], but fails with `expected` if not even the first
+/// character satisfies `pred`.
+pub fn take_while1<'a>(
+    expected: &'static str,
+    pred: impl FnMut(char) -> bool,
+) -> impl Parser<'a, &'a str> {
+    let mut take = take_while(pred);
+    move |input: &'a str| match take.parse(input)? {
+        ("", _) => fail(input, expected),
+        ok => Ok(ok),
+    }
+}
+
+/// Matches one or more letters.
+pub fn alpha<'a>() -> impl Parser<'a, &'a str> {
+    take_while1("letters", char::is_alphabetic)
+}
+
+/// Matches `parser` if possible, without consuming anything otherwise.
+pub fn opt<'a, T>(mut parser: impl Parser<'a, T>) -> impl Parser<'a, Option<T>> {
+    move |input: &'a str| match parser.parse(input) {
+        Ok((value, rest)) => Ok((Some(value), rest)),
+        Err(_) => Ok((None, input)),
+    }
+}
+
+/// Matches `first` and then `second`.
+pub fn pair<'a, A, B>(
+    mut first: impl Parser<'a, A>,
+    mut second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: &'a str| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Ok(((a, b), rest))
+    }
+}
+
+/// Matches `first`, `separator` and `second`, keeping both sides.
+pub fn separated_pair<'a, A, S, B>(
+    first: impl Parser<'a, A>,
+    separator: impl Parser<'a, S>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    pair(terminated(first, separator), second)
+}
+
+/// Matches `prefix` and then `parser`, keeping only the latter.
+pub fn preceded<'a, P, T>(
+    prefix: impl Parser<'a, P>,
+    parser: impl Parser<'a, T>,
+) -> impl Parser<'a, T> {
+    pair(prefix, parser).map(|(_, value)| value)
+}
+
+/// Matches `parser` and then `suffix`, keeping only the former.
+pub fn terminated<'a, T, S>(
+    parser: impl Parser<'a, T>,
+    suffix: impl Parser<'a, S>,
+) -> impl Parser<'a, T> {
+    pair(parser, suffix).map(|(value, _)| value)
+}
+
+/// Matches `parser` between `open` and `close`, e.g. in parentheses.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    preceded(open, terminated(parser, close))
+}
+
+/// Matches one or more `item`s with a `separator` between each two. A
+/// separator after the last item is left in the input.
+pub fn separated<'a, T, S>(
+    mut item: impl Parser<'a, T>,
+    mut separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: &'a str| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Ok((_, after_separator)) = separator.parse(rest) {
+            let Ok((next, after)) = item.parse(after_separator) else {
+                break;
+            };
+            items.push(next);
+            rest = after;
+        }
+        Ok((items, rest))
+    }
+}
+
+/// Returns every match of `parser` in `input`, skipping whatever does not
+/// match. Matches do not overlap.
+pub fn find_all<'a, T, P>(mut parser: P, mut input: &'a str) -> impl Iterator<Item = T> + 'a
+where
+    P: Parser<'a, T> + 'a,
+    T: 'a,
+{
+    iter::from_fn(move || {
+        while !input.is_empty() {
+            match parser.parse(input) {
+                Ok((value, rest)) => {
+                    input = if rest.len() < input.len() {
+                        rest
+                    } else {
+                        skip_char(input)
+                    };
+                    return Some(value);
+                }
+                Err(_) => input = skip_char(input),
+            }
+        }
+        None
+    })
+}
+
+fn skip_char(s: &str) -> &str {
+    let mut chars = s.chars();
+    chars.next();
+    chars.as_str()
+}
+
+/// Parses all of `input`, apart from a trailing newline.
+///
+/// Returns [`Error::Unexpected`] with the line and column where the parser
+/// failed or where input is left over.
+pub fn parse_all<'a, T>(mut parser: impl Parser<'a, T>, input: &'a str) -> Result<T> {
+    let input = input.trim_end_matches(['\r', '\n']);
+    match parser.parse(input) {
+        Ok((value, "")) => Ok(value),
+        Ok((_, rest)) => Err(unexpected(input, rest, "end of input")),
+        Err(failure) => Err(unexpected(input, failure.rest, &failure.expected)),
+    }
+}
+
+fn unexpected(input: &str, rest: &str, expected: &str) -> Error {
+    let parsed = &input[..input.len() - rest.len()];
+    let line_start = parsed.rfind('\n').map_or(0, |i| i + 1);
+    Error::Unexpected {
+        line: parsed.matches('\n').count() + 1,
+        col: parsed[line_start..].chars().count() + 1,
+        expected: expected.to_string(),
+        found: rest.lines().next().unwrap_or_default().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    enum Instruction {
+        Mul(u32, u32),
+        Do,
+        Dont,
+    }
+
+    fn instruction<'a>() -> impl Parser<'a, Instruction> {
+        delimited(
+            tag("mul("),
+            separated_pair(integer(), tag(","), integer()),
+            tag(")"),
+        )
+        .map(|(a, b)| Instruction::Mul(a, b))
+        .or(tag("do()").value(Instruction::Do))
+        .or(tag("don't()").value(Instruction::Dont))
+    }
+
+    #[test]
+    fn tags_and_numbers() {
+        assert_eq!(tag("ab").parse("abc"), Ok(("ab", "c")));
+        assert_eq!(integer::<i32>().parse("-12,3"), Ok((-12, ",3")));
+        assert_eq!(
+            integer::<u32>().parse("-12").unwrap_err().expected,
+            "a number"
+        );
+        assert_eq!(
+            integer::<u8>().parse("300").unwrap_err().expected,
+            "a number in range"
+        );
+        assert_eq!(tag("x").parse("y").unwrap_err().expected, "\"x\"");
+    }
+
+    #[test]
+    fn alternatives() {
+        let mut parser = instruction();
+        assert_eq!(parser.parse("do()x"), Ok((Instruction::Do, "x")));
+        assert_eq!(parser.parse("don't()"), Ok((Instruction::Dont, "")));
+        let failure = parser.parse("mul(1;2)").unwrap_err();
+        // The failure of the alternative that got furthest is kept.
+        assert_eq!((failure.rest, failure.expected.as_str()), (";2)", "\",\""));
+        let failure = parser.parse("nop").unwrap_err();
+        assert_eq!(failure.expected, "\"mul(\" or \"do()\" or \"don't()\"");
+    }
+
+    #[test]
+    fn scanning_noise() {
+        let input = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let found: Vec<_> = find_all(instruction(), input).collect();
+        assert_eq!(
+            found,
+            [
+                Instruction::Mul(2, 4),
+                Instruction::Dont,
+                Instruction::Mul(5, 5),
+                Instruction::Mul(11, 8),
+                Instruction::Do,
+                Instruction::Mul(8, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn optional() {
+        let mut signed = pair(opt(tag("+")), integer::<u8>());
+        assert_eq!(signed.parse("+5"), Ok(((Some("+"), 5), "")));
+        assert_eq!(signed.parse("5"), Ok(((None, 5), "")));
+    }
+
+    #[test]
+    fn lists() {
+        let mut list = separated(integer::<u8>(), tag(","));
+        assert_eq!(list.parse("1,2,3,"), Ok((vec![1, 2, 3], ",")));
+        assert!(list.parse("").is_err());
+
+        let edges = parse_all(
+            separated(separated_pair(alpha(), tag("-"), alpha()), tag("\n")),
+            "kh-tc\nqp-kh\n",
+        );
+        assert_eq!(edges.unwrap(), [("kh", "tc"), ("qp", "kh")]);
+    }
+
+    #[test]
+    fn take() {
+        let mut word = take_while1("a word", |c| c.is_ascii_lowercase());
+        assert_eq!(word.parse("abc1"), Ok(("abc", "1")));
+        assert_eq!(word.parse("1").unwrap_err().expected, "a word");
+        assert_eq!(take_while(|c| c == ' ').parse("x"), Ok(("", "x")));
+    }
+
+    #[test]
+    fn errors() {
+        let edge = || separated_pair(alpha(), tag("-"), alpha());
+        let err = parse_all(edge(), "kh-tc-x").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected end of input at line 1, column 6, found \"-x\""
+        );
+
+        let two_lines = separated_pair(edge(), tag("\n"), edge());
+        let err = parse_all(two_lines, "kh-tc\nqp-12").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Unexpected {
+                line: 2,
+                col: 4,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Expected letters at line 2, column 4, found \"12\""
+        );
+    }
+}
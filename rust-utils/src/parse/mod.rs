@@ -0,0 +1,207 @@
+//! Parses puzzle input: numbers regardless of what is around them, lines
+//! that follow a pattern with [`parse!`](crate::parse!), and inputs made of
+//! blocks separated by blank lines. [`combinator`] has building blocks for
+//! anything more structured.
+
+pub mod combinator;
+mod pattern;
+mod sections;
+
+pub use pattern::{check_pattern, fields, scan, Field, FromFields};
+pub use sections::{each_section, parse_sections, sections, SectionParsers};
+
+use crate::{Error, Result};
+use num_traits::{PrimInt, Signed, Unsigned};
+use std::{fmt, iter, str::FromStr};
+
+/// Returns the unsigned numbers in `s`, which are all runs of ASCII digits.
+/// A `-` is not part of a number, so `3-4` gives `3` and `4`.
+pub fn numbers<T>(s: &str) -> impl Iterator<Item = Result<T>> + '_
+where
+    T: FromStr + Unsigned,
+    T::Err: fmt::Display,
+{
+    tokens(s, false).map(|text| parse_number(text))
+}
+
+/// Returns the numbers in `s`, where a `-` directly in front of digits makes
+/// the number negative. That is, unless it follows a letter or digit, so that
+/// ranges like `3-4` still give `3` and `4`.
+pub fn signed_numbers<T>(s: &str) -> impl Iterator<Item = Result<T>> + '_
+where
+    T: FromStr + PrimInt + Signed,
+    T::Err: fmt::Display,
+{
+    tokens(s, true).map(|text| parse_number(text))
+}
+
+/// Returns exactly `N` numbers from `s`, e.g. the four of `p=0,4 v=3,-3`.
+/// Negative numbers are only recognised if `T` is a signed type.
+pub fn n_numbers<T, const N: usize>(s: &str) -> Result<[T; N]>
+where
+    T: FromStr + PrimInt,
+    T::Err: fmt::Display,
+{
+    let signed = T::min_value() < T::zero();
+    let numbers = tokens(s, signed)
+        .map(|text| parse_number(text))
+        .collect::<Result<Vec<T>>>()?;
+    let found = numbers.len();
+    numbers
+        .try_into()
+        .map_err(|_| Error::NumberCount { expected: N, found })
+}
+
+/// Returns the text of every number in `s`, see [`numbers`] and
+/// [`signed_numbers`].
+fn tokens(s: &str, signed: bool) -> impl Iterator<Item = &str> + '_ {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    iter::from_fn(move || {
+        while i < bytes.len() {
+            let start = i;
+            let sign = signed
+                && bytes[i] == b'-'
+                && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric())
+                && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+            i += 1;
+            if sign || bytes[start].is_ascii_digit() {
+                while i < bytes.len() && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                return Some(&s[start..i]);
+            }
+        }
+        None
+    })
+}
+
+fn parse_number<T>(text: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse().map_err(|err: T::Err| Error::InvalidNumber {
+        text: text.to_string(),
+        message: err.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::OrPanic;
+
+    fn all<T: FromStr + Unsigned>(s: &str) -> Vec<T>
+    where
+        T::Err: fmt::Display,
+    {
+        numbers(s).collect::<Result<_>>().unwrap()
+    }
+
+    #[test]
+    fn leading_zeros() {
+        let nums: Vec<u32> = all("007 0042");
+        assert_eq!(nums, vec![7, 42]);
+    }
+
+    #[test]
+    fn trailing_zeros() {
+        let nums: Vec<u32> = all("700 4200");
+        assert_eq!(nums, vec![700, 4200]);
+    }
+
+    #[test]
+    fn newlines() {
+        let nums: Vec<u32> = all("12\n34\r\n56");
+        assert_eq!(nums, vec![12, 34, 56]);
+    }
+
+    #[test]
+    fn mixed_content() {
+        let nums: Vec<u32> = all("12ab34cd56");
+        assert_eq!(nums, vec![12, 34, 56]);
+    }
+
+    #[test]
+    fn empty_string() {
+        let nums: Vec<u32> = all("");
+        assert!(nums.is_empty());
+    }
+
+    #[test]
+    fn no_numbers() {
+        let nums: Vec<u32> = all("abc def");
+        assert!(nums.is_empty());
+    }
+
+    #[test]
+    fn unsigned_ignores_minus() {
+        let nums: Vec<u8> = all("v=3,-3");
+        assert_eq!(nums, vec![3, 3]);
+    }
+
+    #[test]
+    fn signed() {
+        let nums: Vec<i64> = signed_numbers("p=0,4 v=3,-3 -7")
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(nums, vec![0, 4, 3, -3, -7]);
+    }
+
+    #[test]
+    fn signed_ranges() {
+        let nums: Vec<i32> = signed_numbers("1-3 a, x-2 --5 - 6")
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(nums, vec![1, 3, 2, -5, 6]);
+    }
+
+    #[test]
+    fn wide_types() {
+        let big: Vec<u128> = all("340282366920938463463374607431768211455");
+        assert_eq!(big, vec![u128::MAX]);
+        let [min, max] = n_numbers::<i128, 2>(
+            "-170141183460469231731687303715884105728..170141183460469231731687303715884105727",
+        )
+        .unwrap();
+        assert_eq!((min, max), (i128::MIN, i128::MAX));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut nums = numbers::<u8>("255 256");
+        assert_eq!(nums.next().unwrap().unwrap(), 255);
+        let err = nums.next().unwrap().unwrap_err();
+        assert!(matches!(err, Error::InvalidNumber { ref text, .. } if text == "256"));
+        assert_eq!(
+            err.to_string(),
+            "Invalid number \"256\": number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn fixed_count() {
+        assert_eq!(n_numbers::<i64, 4>("p=0,4 v=3,-3").unwrap(), [0, 4, 3, -3]);
+        assert_eq!(n_numbers::<u64, 2>("a-1 b-2").unwrap(), [1, 2]);
+        assert_eq!(n_numbers::<u64, 0>("none").unwrap(), []);
+
+        let err = n_numbers::<u32, 3>("1 2").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::NumberCount {
+                expected: 3,
+                found: 2
+            }
+        ));
+        assert_eq!(err.to_string(), "Expected 3 numbers, found 2");
+        assert!(n_numbers::<u32, 1>("1 2").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected 2 numbers, found 1")]
+    fn or_panic() {
+        assert_eq!(n_numbers::<u8, 1>("x7").or_panic(), [7]);
+        n_numbers::<u8, 2>("x7").or_panic();
+    }
+}
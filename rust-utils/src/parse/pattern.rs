@@ -0,0 +1,292 @@
+use crate::{Error, Result};
+use std::{fmt, str::FromStr};
+
+/// A field of the input matched by a `{}` of a pattern.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Field<'a> {
+    pub text: &'a str,
+    /// The column of the field's first character, starting at 1.
+    pub col: usize,
+}
+
+impl Field<'_> {
+    /// Parses the text of the field, reporting its column on failure.
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.text
+            .parse()
+            .map_err(|err: T::Err| Error::InvalidField {
+                col: self.col,
+                text: self.text.to_string(),
+                message: err.to_string(),
+            })
+    }
+}
+
+/// Values that can be built from the fields matched by a pattern: a single
+/// value or a tuple for a pattern with several `{}`.
+pub trait FromFields: Sized {
+    /// The number of `{}` in a matching pattern.
+    const LEN: usize;
+
+    fn from_fields(fields: &[Field<'_>]) -> Result<Self>;
+}
+
+macro_rules! impl_from_fields_single {
+    ($($t:ty),*) => {
+        $(
+            impl FromFields for $t {
+                const LEN: usize = 1;
+
+                fn from_fields(fields: &[Field<'_>]) -> Result<Self> {
+                    fields[0].parse()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_fields_single!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, String
+);
+
+macro_rules! impl_from_fields_tuple {
+    ($len:literal; $($t:ident $i:tt),*) => {
+        impl<$($t),*> FromFields for ($($t,)*)
+        where
+            $($t: FromStr, $t::Err: fmt::Display,)*
+        {
+            const LEN: usize = $len;
+
+            fn from_fields(fields: &[Field<'_>]) -> Result<Self> {
+                Ok(($(fields[$i].parse::<$t>()?,)*))
+            }
+        }
+    };
+}
+
+impl_from_fields_tuple!(1; A 0);
+impl_from_fields_tuple!(2; A 0, B 1);
+impl_from_fields_tuple!(3; A 0, B 1, C 2);
+impl_from_fields_tuple!(4; A 0, B 1, C 2, D 3);
+impl_from_fields_tuple!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_fields_tuple!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_fields_tuple!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_fields_tuple!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Matches `input` against `pattern`, see [`parse!`](crate::parse!).
+///
+/// # Panics
+///
+/// If the number of `{}` in the pattern differs from what `T` needs.
+pub fn scan<T: FromFields>(pattern: &str, input: &str) -> Result<T> {
+    let fields = fields(pattern, input)?;
+    assert_eq!(
+        fields.len(),
+        T::LEN,
+        "Pattern {:?} has {} fields, but {} are parsed from it",
+        pattern,
+        fields.len(),
+        T::LEN
+    );
+    T::from_fields(&fields)
+}
+
+/// Returns the parts of `input` matched by the `{}` of `pattern`. Every
+/// field reaches up to the first occurrence of the text following it in the
+/// pattern, the last one to the end of the line if nothing follows it.
+pub fn fields<'a>(pattern: &str, input: &'a str) -> Result<Vec<Field<'a>>> {
+    let input = input.trim_end_matches(['\r', '\n']);
+    let mut literals = pattern.split("{}");
+    let mut pos = expect(input, 0, literals.next().unwrap_or_default())?;
+    let mut fields = Vec::new();
+
+    for literal in literals {
+        let end = if literal.is_empty() {
+            input.len()
+        } else {
+            match input[pos..].find(literal) {
+                Some(offset) => pos + offset,
+                None => return Err(partial_mismatch(input, pos, literal)),
+            }
+        };
+        fields.push(Field {
+            text: &input[pos..end],
+            col: column(input, pos),
+        });
+        pos = end + literal.len();
+    }
+    if pos < input.len() {
+        return Err(mismatch(input, pos, ""));
+    }
+    Ok(fields)
+}
+
+/// Checks that `literal` follows at `pos` and returns the position after it.
+fn expect(input: &str, pos: usize, literal: &str) -> Result<usize> {
+    let rest = &input[pos..];
+    if rest.starts_with(literal) {
+        return Ok(pos + literal.len());
+    }
+    let same = rest
+        .char_indices()
+        .zip(literal.chars())
+        .find(|((_, a), b)| a != b)
+        .map_or(rest.len(), |((i, _), _)| i);
+    Err(mismatch(input, pos + same, &literal[same..]))
+}
+
+/// Reports a literal that does not occur after `pos` where its longest
+/// occurring beginning breaks off, or at the end of the line.
+fn partial_mismatch(input: &str, pos: usize, literal: &str) -> Error {
+    let (offset, len) = (1..literal.len())
+        .rev()
+        .filter(|&len| literal.is_char_boundary(len))
+        .find_map(|len| {
+            let offset = input[pos..].find(&literal[..len])?;
+            Some((offset, len))
+        })
+        .unwrap_or((input.len() - pos, 0));
+    mismatch(input, pos + offset + len, &literal[len..])
+}
+
+fn mismatch(input: &str, pos: usize, expected: &str) -> Error {
+    Error::Mismatch {
+        col: column(input, pos),
+        expected: expected.to_string(),
+        found: input[pos..].to_string(),
+    }
+}
+
+fn column(input: &str, pos: usize) -> usize {
+    input[..pos].chars().count() + 1
+}
+
+/// Checks a pattern at compile time, used by [`parse!`](crate::parse!).
+///
+/// # Panics
+///
+/// If two `{}` follow each other directly, as it is unclear where the first
+/// field would end.
+pub const fn check_pattern(pattern: &str) {
+    let bytes = pattern.as_bytes();
+    let mut i = 0;
+    while i + 3 < bytes.len() {
+        if bytes[i] == b'{' && bytes[i + 1] == b'}' && bytes[i + 2] == b'{' && bytes[i + 3] == b'}'
+        {
+            panic!("Fields in a pattern must be separated by some text");
+        }
+        i += 1;
+    }
+}
+
+/// Parses a line by a pattern in which every `{}` stands for a field, e.g.
+/// `"Button {}: X+{}, Y+{}"`. The fields are parsed with [`FromStr`] into a
+/// tuple, or into a single value for patterns with one field, so the types
+/// usually come from the binding:
+///
+/// ```
+/// let (button, x, y): (char, i64, i64) =
+///     utils::parse!("Button {}: X+{}, Y+{}", "Button A: X+94, Y+34").unwrap();
+/// assert_eq!((button, x, y), ('A', 94, 34));
+///
+/// let register: u64 = utils::parse!("Register A: {}", "Register A: 729").unwrap();
+/// assert_eq!(register, 729);
+///
+/// let err = utils::parse!("{}|{}", "47,53").map(|(_, _): (u32, u32)| ()).unwrap_err();
+/// assert_eq!(err.to_string(), "Expected \"|\" at column 6, found end of line");
+/// ```
+///
+/// Returns [`Error::Mismatch`] with the column where the input differs from
+/// the pattern and [`Error::InvalidField`] for fields that do not parse.
+///
+/// Fields must be separated by some text, which is checked at compile time:
+///
+/// ```compile_fail
+/// let (a, b): (u8, u8) = utils::parse!("{}{}", "12").unwrap();
+/// ```
+#[macro_export]
+macro_rules! parse {
+    ($pattern:literal, $input:expr $(,)?) => {{
+        const _: () = $crate::parse::check_pattern($pattern);
+        $crate::parse::scan($pattern, $input)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tuple() {
+        let (button, x, y): (char, i64, i64) =
+            scan("Button {}: X+{}, Y+{}", "Button B: X+22, Y+67").unwrap();
+        assert_eq!((button, x, y), ('B', 22, 67));
+    }
+
+    #[test]
+    fn words() {
+        let (a, op, b, out): (String, String, String, String) =
+            crate::parse!("{} {} {} -> {}", "x00 AND y00 -> z00\n").unwrap();
+        assert_eq!([a, op, b, out], ["x00", "AND", "y00", "z00"]);
+    }
+
+    #[test]
+    fn field_columns() {
+        let fields = fields("p={},{} v={},{}", "p=0,4 v=3,-3").unwrap();
+        let cols: Vec<_> = fields.iter().map(|field| (field.text, field.col)).collect();
+        assert_eq!(cols, [("0", 3), ("4", 5), ("3", 9), ("-3", 11)]);
+    }
+
+    #[test]
+    fn literal_mismatch() {
+        let err =
+            scan::<(char, u32, u32)>("Button {}: X+{}, Y+{}", "Button A: X+94, Y=34").unwrap_err();
+        assert!(matches!(
+            err,
+            Error::Mismatch { col: 18, ref expected, ref found } if expected == "+" && found == "=34"
+        ));
+
+        let err = scan::<(u32, u32)>("{} -> {}", "1 => 2").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected \"-> \" at column 3, found \"=> 2\""
+        );
+
+        let err = scan::<u32>("Register A: {}", "Register B: 1").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected \"A: \" at column 10, found \"B: 1\""
+        );
+    }
+
+    #[test]
+    fn trailing_input() {
+        let err = scan::<u32>("{}|", "47|53").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Expected end of line at column 4, found \"53\""
+        );
+    }
+
+    #[test]
+    fn invalid_field() {
+        let err = crate::parse!("{}|{}", "47|5x")
+            .map(|(_, _): (u32, u32)| ())
+            .unwrap_err();
+        assert!(matches!(err, Error::InvalidField { col: 4, .. }));
+        assert_eq!(
+            err.to_string(),
+            "Invalid value \"5x\" at column 4: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "has 2 fields, but 3 are parsed")]
+    fn wrong_arity() {
+        let _ = scan::<(u8, u8, u8)>("{}|{}", "1|2");
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{Error, Result};
+use std::iter;
+
+/// Returns the blocks of `input` that are separated by blank lines, without
+/// their trailing newline. Lines consisting only of whitespace count as
+/// blank. Several blank lines in a row separate just once, and blank lines at
+/// the start or end are ignored.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> + '_ {
+    let mut lines = input
+        .split_inclusive('\n')
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some((start, line))
+        })
+        .peekable();
+    let end_of = |(start, line): (usize, &str)| start + line.trim_end_matches(['\r', '\n']).len();
+    let is_blank = |(_, line): &(usize, &str)| line.trim().is_empty();
+
+    iter::from_fn(move || {
+        let first = lines.find(|line| !is_blank(line))?;
+        let mut end = end_of(first);
+        while let Some(line) = lines.next_if(|line| !is_blank(line)) {
+            end = end_of(line);
+        }
+        Some(&input[first.0..end])
+    })
+}
+
+/// Parses every section of `input` with `f`, for inputs made of blocks of
+/// the same kind. Errors tell which section they are from.
+pub fn each_section<'a, T>(
+    input: &'a str,
+    mut f: impl FnMut(&'a str) -> Result<T> + 'a,
+) -> impl Iterator<Item = Result<T>> + 'a {
+    sections(input)
+        .enumerate()
+        .map(move |(index, section)| f(section).map_err(|err| in_section(index, err)))
+}
+
+/// Parses inputs made of sections of different kinds with one parser per
+/// section, e.g. rules followed by updates:
+///
+/// ```
+/// use utils::parse::{self, parse_sections};
+///
+/// let input = "47|53\n97|13\n\n75,47,61";
+/// let (rules, updates) = parse_sections(
+///     input,
+///     (
+///         |s: &str| s.lines().map(|line| utils::parse!("{}|{}", line)).collect(),
+///         |s: &str| parse::numbers(s).collect(),
+///     ),
+/// )
+/// .unwrap();
+/// let rules: Vec<(u32, u32)> = rules;
+/// let updates: Vec<u32> = updates;
+/// assert_eq!(rules, [(47, 53), (97, 13)]);
+/// assert_eq!(updates, [75, 47, 61]);
+/// ```
+///
+/// Parsers that return parts of their section must be functions rather than
+/// closures, as the lifetimes of a closure's `&str` argument and result are
+/// not tied together.
+///
+/// Returns [`Error::SectionCount`] if there are more or fewer sections than
+/// parsers, and wraps errors of the parsers in [`Error::Section`].
+pub fn parse_sections<'a, P: SectionParsers<'a>>(input: &'a str, parsers: P) -> Result<P::Output> {
+    let sections: Vec<&str> = sections(input).collect();
+    if sections.len() != P::LEN {
+        return Err(Error::SectionCount {
+            expected: P::LEN,
+            found: sections.len(),
+        });
+    }
+    parsers.parse(&sections)
+}
+
+/// A tuple of section parsers, see [`parse_sections`].
+pub trait SectionParsers<'a> {
+    type Output;
+    const LEN: usize;
+
+    fn parse(self, sections: &[&'a str]) -> Result<Self::Output>;
+}
+
+macro_rules! impl_section_parsers {
+    ($len:literal; $($f:ident $t:ident $i:tt),*) => {
+        impl<'a, $($f, $t),*> SectionParsers<'a> for ($($f,)*)
+        where
+            $($f: FnOnce(&'a str) -> Result<$t>,)*
+        {
+            type Output = ($($t,)*);
+            const LEN: usize = $len;
+
+            fn parse(self, sections: &[&'a str]) -> Result<Self::Output> {
+                Ok(($(
+                    (self.$i)(sections[$i]).map_err(|err| in_section($i, err))?,
+                )*))
+            }
+        }
+    };
+}
+
+impl_section_parsers!(1; FA A 0);
+impl_section_parsers!(2; FA A 0, FB B 1);
+impl_section_parsers!(3; FA A 0, FB B 1, FC C 2);
+impl_section_parsers!(4; FA A 0, FB B 1, FC C 2, FD D 3);
+impl_section_parsers!(5; FA A 0, FB B 1, FC C 2, FD D 3, FE E 4);
+impl_section_parsers!(6; FA A 0, FB B 1, FC C 2, FD D 3, FE E 4, FF F 5);
+
+fn in_section(index: usize, err: Error) -> Error {
+    Error::Section {
+        section: index + 1,
+        error: Box::new(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use std::error::Error as _;
+
+    #[test]
+    fn split() {
+        let input = "a\nb\n\nc\r\n\r\n\n  \nd\ne\n";
+        assert_eq!(sections(input).collect::<Vec<_>>(), ["a\nb", "c", "d\ne"]);
+        assert_eq!(sections("\n\nx\n\n").collect::<Vec<_>>(), ["x"]);
+        assert_eq!(
+            sections("Register A: 729\r\n\r\nProgram: 0,1\r\n").collect::<Vec<_>>(),
+            ["Register A: 729", "Program: 0,1"]
+        );
+        assert_eq!(sections("").count(), 0);
+        assert_eq!(sections("\n \n").count(), 0);
+    }
+
+    #[test]
+    fn each() {
+        let sizes: Vec<usize> = each_section("#.\n##\n\n..\n.#", |s| Ok(s.matches('#').count()))
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(sizes, [3, 1]);
+
+        let err = each_section("1\n\n2\n\nx", parse::n_numbers::<u8, 1>)
+            .collect::<Result<Vec<_>>>()
+            .unwrap_err();
+        assert!(matches!(err, Error::Section { section: 3, .. }));
+        assert_eq!(err.to_string(), "Section 3: Expected 1 numbers, found 0");
+    }
+
+    #[test]
+    fn tuple() {
+        let input = "Register A: 729\n\nProgram: 0,1,5,4,3,0\n";
+        let (register, program) = parse_sections(
+            input,
+            (
+                |s| crate::parse!("Register A: {}", s),
+                |s| parse::numbers(s).collect::<Result<Vec<u8>>>(),
+            ),
+        )
+        .unwrap();
+        let register: u64 = register;
+        assert_eq!(register, 729);
+        assert_eq!(program, [0, 1, 5, 4, 3, 0]);
+    }
+
+    #[test]
+    fn failing_section() {
+        let input = "1,2\n\n3,x";
+        let err = parse_sections(
+            input,
+            (
+                |s| crate::parse!("{},{}", s).map(|(_, _): (u8, u8)| ()),
+                |s| crate::parse!("{},{}", s).map(|(_, _): (u8, u8)| ()),
+            ),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Section 2: Invalid value \"x\" at column 3: invalid digit found in string"
+        );
+        let Error::Section { section, error } = &err else {
+            panic!("Expected a section error, got {:?}", err);
+        };
+        assert_eq!(*section, 2);
+        assert!(matches!(**error, Error::InvalidField { col: 3, .. }));
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn section_count() {
+        let err = parse_sections("a\n\nb\n\nc", (|s| Ok(s), |s| Ok(s))).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::SectionCount {
+                expected: 2,
+                found: 3
+            }
+        ));
+        assert_eq!(err.to_string(), "Expected 2 sections, found 3");
+    }
+}
@@ -0,0 +1,221 @@
+use crate::solution::Part;
+use std::fmt::Write;
+
+/// The examples and expected answers of a puzzle description page.
+///
+/// Every part is an `<article>` on the page, the examples are its
+/// `<pre><code>` blocks and the expected answer is the last highlighted
+/// `<code><em>` in it, which is where the text states the result of the
+/// example.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Puzzle {
+    pub parts: Vec<Description>,
+}
+
+/// A single part of a [`Puzzle`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Description {
+    pub examples: Vec<String>,
+    pub answer: Option<String>,
+}
+
+/// An example input, named like the example files of the days.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub file: String,
+    pub part: Part,
+    pub input: String,
+}
+
+impl Puzzle {
+    pub fn parse(html: &str) -> Self {
+        let parts = elements(html, "<article", "</article>")
+            .map(|article| Description {
+                examples: elements(article, "<pre><code>", "</code></pre>")
+                    .map(|code| text(code).trim_end().to_string())
+                    .collect(),
+                answer: answer(article),
+            })
+            .collect();
+        Self { parts }
+    }
+
+    /// Returns the distinct examples, named `example.txt` if there is only one
+    /// and `example<part>_<n>.txt` otherwise.
+    pub fn examples(&self) -> Vec<Example> {
+        let mut examples: Vec<Example> = Vec::new();
+        for (part, description) in Part::ALL.into_iter().zip(&self.parts) {
+            for input in &description.examples {
+                if examples.iter().all(|example| example.input != *input) {
+                    let n = examples.iter().filter(|e| e.part == part).count() + 1;
+                    examples.push(Example {
+                        file: format!("example{}_{}.txt", part, n),
+                        part,
+                        input: input.clone(),
+                    });
+                }
+            }
+        }
+        if let [example] = examples.as_mut_slice() {
+            example.file = "example.txt".to_string();
+        }
+        examples
+    }
+
+    /// Returns the example file that belongs to the answer of a part, which is
+    /// the first example of that part or else the last one before it.
+    pub fn example_for(&self, part: Part) -> Option<Example> {
+        let index = Part::ALL.iter().position(|&p| p == part)?;
+        let input = self
+            .parts
+            .get(..=index)?
+            .iter()
+            .rev()
+            .find_map(|description| description.examples.first())?;
+        self.examples()
+            .into_iter()
+            .find(|example| example.input == *input)
+    }
+
+    /// Renders the manifest of the expected answers as TOML.
+    pub fn manifest(&self) -> String {
+        let mut manifest = String::new();
+        for (part, description) in Part::ALL.into_iter().zip(&self.parts) {
+            let _ = writeln!(manifest, "[part{}]", part);
+            if let Some(example) = self.example_for(part) {
+                let _ = writeln!(manifest, "example = {:?}", example.file);
+            }
+            if let Some(answer) = &description.answer {
+                let _ = writeln!(manifest, "answer = {:?}", answer);
+            }
+            manifest.push('\n');
+        }
+        manifest.trim_end().to_string() + "\n"
+    }
+}
+
+/// Returns the contents of all elements between `open` and `close`. The
+/// opening tag may have attributes.
+fn elements<'a>(html: &'a str, open: &'a str, close: &'a str) -> impl Iterator<Item = &'a str> {
+    let mut rest = html;
+    std::iter::from_fn(move || {
+        let start = rest.find(open)?;
+        let after = &rest[start + open.len()..];
+        let inner = if open.ends_with('>') {
+            after
+        } else {
+            &after[after.find('>')? + 1..]
+        };
+        let end = inner.find(close)?;
+        rest = &inner[end + close.len()..];
+        Some(&inner[..end])
+    })
+}
+
+/// The last highlighted `<code><em>` or `<em><code>` of an article.
+fn answer(article: &str) -> Option<String> {
+    [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ]
+    .into_iter()
+    .filter_map(|(open, close)| {
+        let start = article.rfind(open)?;
+        let inner = &article[start + open.len()..];
+        Some((start, text(&inner[..inner.find(close)?])))
+    })
+    .max_by_key(|(start, _)| *start)
+    .map(|(_, answer)| answer)
+}
+
+/// Strips the tags and decodes the entities that occur on the pages.
+pub(crate) fn text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HTML: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+</code></pre>
+<p>Pairs: <code>3</code> and <code>4</code>, a distance of <em>1</em>.</p>
+<p>In the example above, this is <code>0 + 1</code> = <code><em>1</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>2756096</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>p=0,4 v=3,-3
+&lt;<em>v</em>&gt; &amp;
+</code></pre>
+<p>So, the similarity score is <em><code>31</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn parse_parts() {
+        let puzzle = Puzzle::parse(HTML);
+        assert_eq!(
+            puzzle.parts,
+            vec![
+                Description {
+                    examples: vec!["3   4\n4   3".to_string()],
+                    answer: Some("1".to_string()),
+                },
+                Description {
+                    examples: vec!["p=0,4 v=3,-3\n<v> &".to_string()],
+                    answer: Some("31".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn example_names() {
+        let puzzle = Puzzle::parse(HTML);
+        let files: Vec<String> = puzzle.examples().into_iter().map(|e| e.file).collect();
+        assert_eq!(files, vec!["example1_1.txt", "example2_1.txt"]);
+
+        let single = Puzzle::parse(
+            &HTML.replace("p=0,4 v=3,-3\n&lt;<em>v</em>&gt; &amp;\n", "3   4\n4   3\n"),
+        );
+        let files: Vec<String> = single.examples().into_iter().map(|e| e.file).collect();
+        assert_eq!(files, vec!["example.txt"]);
+        assert_eq!(single.example_for(Part::Two).unwrap().file, "example.txt");
+    }
+
+    #[test]
+    fn manifest() {
+        let puzzle = Puzzle::parse(HTML);
+        assert_eq!(
+            puzzle.manifest(),
+            "[part1]\nexample = \"example1_1.txt\"\nanswer = \"1\"\n\n[part2]\nexample = \"example2_1.txt\"\nanswer = \"31\"\n"
+        );
+    }
+
+    #[test]
+    fn only_first_part() {
+        let html = HTML.split("</article>").next().unwrap().to_string() + "</article>";
+        let puzzle = Puzzle::parse(&html);
+        assert_eq!(puzzle.parts.len(), 1);
+        assert!(puzzle.example_for(Part::Two).is_none());
+        assert_eq!(Puzzle::parse("<html></html>").examples(), vec![]);
+    }
+}
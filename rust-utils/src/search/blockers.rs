@@ -0,0 +1,325 @@
+use super::bfs;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// How [`first_blocking`] finds the blocker that cuts the goal off. All give
+/// the same answer, they differ in speed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Keeps one path to the goal and only searches for a new one when a
+    /// blocker lands on it. Fast if few blockers hit the path.
+    #[default]
+    PathRepair,
+    /// Binary searches for the shortest prefix of blockers that cuts the goal
+    /// off, with a BFS for every tried prefix.
+    BinarySearch,
+    /// Starts with all blockers placed and removes them in reverse order,
+    /// joining the freed nodes into connected components, until start and
+    /// goal are in the same one. Visits every node only once, but needs the
+    /// edges to go both ways.
+    UnionFind,
+}
+
+/// Places `blockers` one after another and returns the index of the first one
+/// after which `goal` can no longer be reached from `start`. Returns `None` if
+/// no blocker cuts the goal off: if it stays reachable, or if it is not
+/// reachable even without blockers.
+///
+/// `neighbors` returns the nodes adjacent to a node, ignoring blockers.
+/// Placing a blocker on a node that is already blocked has no effect.
+pub fn first_blocking<N, I>(
+    start: N,
+    goal: N,
+    blockers: &[N],
+    neighbors: impl FnMut(&N) -> I,
+    strategy: Strategy,
+) -> Option<usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut blocked_at: HashMap<&N, usize> = HashMap::new();
+    for (index, blocker) in blockers.iter().enumerate() {
+        blocked_at.entry(blocker).or_insert(index);
+    }
+    let graph = Graph {
+        start,
+        goal,
+        blocked_at,
+        neighbors,
+    };
+    match strategy {
+        Strategy::PathRepair => path_repair(graph, blockers),
+        Strategy::BinarySearch => binary_search(graph, blockers),
+        Strategy::UnionFind => union_find(graph, blockers),
+    }
+}
+
+struct Graph<'a, N, F> {
+    start: N,
+    goal: N,
+    /// The index of the first blocker on every blocked node.
+    blocked_at: HashMap<&'a N, usize>,
+    neighbors: F,
+}
+
+impl<N, F, I> Graph<'_, N, F>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    /// Whether a node is free after the first `placed` blockers.
+    fn is_free(&self, node: &N, placed: usize) -> bool {
+        self.blocked_at
+            .get(node)
+            .is_none_or(|&index| index >= placed)
+    }
+
+    /// Returns a path from the start to the goal after the first `placed`
+    /// blockers.
+    fn path(&mut self, placed: usize) -> Option<Vec<N>> {
+        if !self.is_free(&self.start, placed) {
+            return None;
+        }
+        let Self {
+            goal,
+            blocked_at,
+            neighbors,
+            ..
+        } = self;
+        bfs(
+            self.start.clone(),
+            |node| {
+                neighbors(node)
+                    .into_iter()
+                    .filter(|next| blocked_at.get(next).is_none_or(|&index| index >= placed))
+            },
+            |node| node == goal,
+        )
+        .path()
+    }
+}
+
+fn path_repair<N, F, I>(mut graph: Graph<'_, N, F>, blockers: &[N]) -> Option<usize>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut path: HashSet<N> = graph.path(0)?.into_iter().collect();
+    for (index, blocker) in blockers.iter().enumerate() {
+        if path.contains(blocker) {
+            match graph.path(index + 1) {
+                Some(repaired) => path = repaired.into_iter().collect(),
+                None => return Some(index),
+            }
+        }
+    }
+    None
+}
+
+fn binary_search<N, F, I>(mut graph: Graph<'_, N, F>, blockers: &[N]) -> Option<usize>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    graph.path(0)?;
+    // The goal is reachable with `low` blockers placed and cut off with
+    // `high` placed.
+    let (mut low, mut high) = (0, blockers.len() + 1);
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if mid <= blockers.len() && graph.path(mid).is_some() {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (high <= blockers.len()).then(|| high - 1)
+}
+
+fn union_find<N, F, I>(mut graph: Graph<'_, N, F>, blockers: &[N]) -> Option<usize>
+where
+    N: Eq + Hash + Clone,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    // Every node that can ever be reached, in the graph without blockers.
+    let nodes: Vec<N> = bfs(graph.start.clone(), &mut graph.neighbors, |_| false)
+        .order()
+        .to_vec();
+    if !nodes.contains(&graph.goal) {
+        return None;
+    }
+    let ids: HashMap<&N, usize> = nodes
+        .iter()
+        .enumerate()
+        .map(|(id, node)| (node, id))
+        .collect();
+    let mut sets = UnionFind::new(nodes.len());
+    let mut free = vec![false; nodes.len()];
+
+    let mut release = |sets: &mut UnionFind, free: &mut [bool], node: &N| {
+        let Some(&id) = ids.get(node) else {
+            return;
+        };
+        free[id] = true;
+        for next in (graph.neighbors)(node) {
+            if let Some(&next_id) = ids.get(&next) {
+                if free[next_id] {
+                    sets.union(id, next_id);
+                }
+            }
+        }
+    };
+    let connected = |sets: &mut UnionFind, free: &[bool]| {
+        let (start, goal) = (ids[&graph.start], ids[&graph.goal]);
+        free[start] && free[goal] && sets.find(start) == sets.find(goal)
+    };
+
+    for node in &nodes {
+        if !graph.blocked_at.contains_key(node) {
+            release(&mut sets, &mut free, node);
+        }
+    }
+    if connected(&mut sets, &free) {
+        return None;
+    }
+    for (index, blocker) in blockers.iter().enumerate().rev() {
+        if graph.blocked_at[blocker] == index {
+            release(&mut sets, &mut free, blocker);
+            if connected(&mut sets, &free) {
+                return Some(index);
+            }
+        }
+    }
+    None
+}
+
+/// Disjoint sets of the numbers `0..len`.
+struct UnionFind {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut id: usize) -> usize {
+        while self.parents[id] != id {
+            self.parents[id] = self.parents[self.parents[id]];
+            id = self.parents[id];
+        }
+        id
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Position;
+
+    const STRATEGIES: [Strategy; 3] = [
+        Strategy::PathRepair,
+        Strategy::BinarySearch,
+        Strategy::UnionFind,
+    ];
+
+    fn first_blocking_in(size: usize, blockers: &[Position]) -> [Option<usize>; 3] {
+        let max = Position::new(size - 1, size - 1);
+        STRATEGIES.map(|strategy| {
+            first_blocking(
+                Position::new(0, 0),
+                max,
+                blockers,
+                |pos| pos.neighbors_contained(max),
+                strategy,
+            )
+        })
+    }
+
+    fn positions(coordinates: &[(usize, usize)]) -> Vec<Position> {
+        coordinates
+            .iter()
+            .map(|&(row, col)| Position::new(row, col))
+            .collect()
+    }
+
+    #[test]
+    fn wall() {
+        // The third blocker closes the wall across the middle row.
+        let blockers = positions(&[(1, 0), (1, 2), (1, 1), (0, 1)]);
+        assert_eq!(first_blocking_in(3, &blockers), [Some(2); 3]);
+    }
+
+    #[test]
+    fn never_blocked() {
+        let blockers = positions(&[(1, 0), (1, 1), (2, 0)]);
+        assert_eq!(first_blocking_in(3, &blockers), [None; 3]);
+        assert_eq!(first_blocking_in(3, &[]), [None; 3]);
+    }
+
+    #[test]
+    fn repeated_blocker() {
+        let blockers = positions(&[(1, 0), (1, 0), (1, 1), (0, 0), (1, 2)]);
+        assert_eq!(first_blocking_in(3, &blockers), [Some(3); 3]);
+    }
+
+    #[test]
+    fn goal_blocked() {
+        let blockers = positions(&[(0, 1), (2, 2)]);
+        assert_eq!(first_blocking_in(3, &blockers), [Some(1); 3]);
+    }
+
+    #[test]
+    fn never_reachable() {
+        let max = Position::new(2, 2);
+        for strategy in STRATEGIES {
+            let res = first_blocking(
+                Position::new(0, 0),
+                Position::new(9, 9),
+                &positions(&[(1, 1)]),
+                |pos| pos.neighbors_contained(max),
+                strategy,
+            );
+            assert_eq!(res, None);
+        }
+    }
+
+    #[test]
+    fn strategies_agree() {
+        // Pseudo-random blockers on a 12x12 grid.
+        let mut state = 7u64;
+        let blockers: Vec<Position> = (0..150)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                Position::new((state >> 33) as usize % 12, (state >> 45) as usize % 12)
+            })
+            .collect();
+        let [repair, binary, union] = first_blocking_in(12, &blockers);
+        assert!(repair.is_some());
+        assert_eq!(repair, binary);
+        assert_eq!(repair, union);
+    }
+}
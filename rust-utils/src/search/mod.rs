@@ -0,0 +1,606 @@
+//! Graph searches over any node type, with the edges given by a successor
+//! function.
+//!
+//! All searches stop as soon as a node satisfying `is_goal` is reached. Pass
+//! `|_| false` to explore everything reachable from the start.
+//! [`dijkstra_all`] keeps every cheapest way of reaching a node instead of
+//! just one, to count or list all optimal paths. [`first_blocking`] finds
+//! when added blockers cut the goal off.
+
+mod blockers;
+
+pub use blockers::{first_blocking, Strategy};
+
+use num_traits::Zero;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// The outcome of a search: the cost of reaching every visited node, how it
+/// was reached, and the goal if one was found.
+#[derive(Clone, Debug)]
+pub struct Search<N, C> {
+    start: N,
+    goal: Option<N>,
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, N>,
+    order: Vec<N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Search<N, C> {
+    fn new(start: N, zero: C) -> Self {
+        Self {
+            start: start.clone(),
+            goal: None,
+            costs: HashMap::from([(start, zero)]),
+            predecessors: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// Returns the goal node that was reached, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Returns the cost of reaching the goal.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// Returns the cost of reaching a node. For BFS and DFS this is the
+    /// number of steps.
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Returns the best known cost of every visited node. Nodes that are
+    /// queued but not expanded when the goal is found may have costs that are
+    /// not optimal.
+    pub fn costs(&self) -> &HashMap<N, C> {
+        &self.costs
+    }
+
+    /// Returns the node every visited node but the start was reached from.
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.predecessors
+    }
+
+    /// Returns the nodes in the order they were expanded.
+    pub fn order(&self) -> &[N] {
+        &self.order
+    }
+
+    /// Returns the path from the start to a visited node, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.costs.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Returns the path from the start to the goal, both included.
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+}
+
+/// Breadth-first search, for graphs where every edge costs the same.
+pub fn bfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        search.order.push(node.clone());
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let cost = search.costs[&node] + 1;
+        for next in successors(&node) {
+            if !search.costs.contains_key(&next) {
+                search.costs.insert(next.clone(), cost);
+                search.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Depth-first search, expanding successors in the order they are returned.
+/// The costs are the depths in the search tree, not the shortest distances.
+pub fn dfs<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut stack = vec![(start, None)];
+
+    while let Some((node, prev)) = stack.pop() {
+        if let Some((prev, depth)) = prev {
+            if search.predecessors.contains_key(&node) || node == search.start {
+                continue;
+            }
+            search.costs.insert(node.clone(), depth);
+            search.predecessors.insert(node.clone(), prev);
+        }
+        search.order.push(node.clone());
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let depth = search.costs[&node] + 1;
+        let mut next: Vec<N> = successors(&node).into_iter().collect();
+        next.reverse();
+        for next in next {
+            if !search.costs.contains_key(&next) {
+                stack.push((next, Some((node.clone(), depth))));
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm, for graphs with non-negative edge costs.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search. `heuristic` estimates the remaining cost to a goal and must
+/// never overestimate it, or the found path may not be the cheapest.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new(start.clone(), C::zero());
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::zero(),
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if search.costs[&node] < cost {
+            // Outdated entry, the node was queued again with a lower cost.
+            continue;
+        }
+        search.order.push(node.clone());
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if search
+                .costs
+                .get(&next)
+                .is_none_or(|&known| next_cost < known)
+            {
+                search.costs.insert(next.clone(), next_cost);
+                search.predecessors.insert(next.clone(), node.clone());
+                queue.push(Queued {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    search
+}
+
+/// Every optimal path from the start to the cheapest goals, as a graph where
+/// each node knows all the nodes it is reached from at its lowest cost.
+#[derive(Clone, Debug)]
+pub struct AllPaths<N, C> {
+    start: N,
+    goals: Vec<N>,
+    costs: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+    order: Vec<N>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> AllPaths<N, C> {
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// Returns all goal nodes reached at the lowest cost.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// Returns the cost of reaching the goals.
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goals.first()?)
+    }
+
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+
+    /// Returns all nodes a node is reached from at its lowest cost.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the number of optimal paths to any of the goals.
+    pub fn count_paths(&self) -> u128 {
+        // Nodes are expanded in order of their cost, so all predecessors of
+        // a node are counted before it.
+        let mut counts: HashMap<&N, u128> = HashMap::from([(&self.start, 1)]);
+        for node in &self.order[1..] {
+            let count = self
+                .predecessors(node)
+                .iter()
+                .map(|prev| counts.get(prev).copied().unwrap_or(0))
+                .sum();
+            counts.insert(node, count);
+        }
+        self.goals.iter().map(|goal| counts[goal]).sum()
+    }
+
+    /// Returns the optimal paths one by one, from the start to a goal with
+    /// both included. There can be exponentially many, they are only found
+    /// when asked for.
+    pub fn paths(&self) -> Paths<'_, N, C> {
+        Paths {
+            all: self,
+            goals: self.goals.iter(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Returns every node that is on at least one optimal path.
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut nodes: HashSet<N> = self.goals.iter().cloned().collect();
+        let mut to_process = self.goals.clone();
+        while let Some(node) = to_process.pop() {
+            for prev in self.predecessors(&node) {
+                if nodes.insert(prev.clone()) {
+                    to_process.push(prev.clone());
+                }
+            }
+        }
+        nodes
+    }
+}
+
+/// The iterator returned by [`AllPaths::paths`].
+pub struct Paths<'a, N, C> {
+    all: &'a AllPaths<N, C>,
+    goals: std::slice::Iter<'a, N>,
+    /// The path currently followed back from a goal, with the index of the
+    /// predecessor to follow next from every node.
+    stack: Vec<(&'a N, usize)>,
+}
+
+impl<N: Eq + Hash + Clone, C: Copy> Iterator for Paths<'_, N, C> {
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((node, next)) = self.stack.last_mut() else {
+                self.stack.push((self.goals.next()?, 0));
+                continue;
+            };
+            let predecessors = self.all.predecessors(node);
+            if predecessors.is_empty() {
+                // Only the start has no predecessors.
+                let path = self.stack.iter().rev().map(|(node, _)| (*node).clone());
+                let path = path.collect();
+                self.stack.pop();
+                return Some(path);
+            }
+            match predecessors.get(*next) {
+                Some(prev) => {
+                    *next += 1;
+                    self.stack.push((prev, 0));
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+    }
+}
+
+/// Dijkstra's algorithm, keeping all optimal paths to the cheapest goals.
+/// The search goes on until every node as cheap as the first goal found is
+/// expanded, so all goals reached at that cost are found. Edge costs must be
+/// positive.
+pub fn dijkstra_all<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> AllPaths<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Zero + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut all = AllPaths {
+        start: start.clone(),
+        goals: Vec::new(),
+        costs: HashMap::from([(start.clone(), C::zero())]),
+        predecessors: HashMap::new(),
+        order: Vec::new(),
+    };
+    let mut goal_cost = None;
+    let mut queue = BinaryHeap::from([Queued {
+        priority: C::zero(),
+        cost: C::zero(),
+        node: start,
+    }]);
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            break;
+        }
+        if all.costs[&node] < cost {
+            continue;
+        }
+        all.order.push(node.clone());
+        if is_goal(&node) {
+            goal_cost = Some(cost);
+            all.goals.push(node);
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            match all.costs.get(&next).map(|known| next_cost.cmp(known)) {
+                Some(Ordering::Greater) => (),
+                Some(Ordering::Equal) => {
+                    all.predecessors.entry(next).or_default().push(node.clone());
+                }
+                None | Some(Ordering::Less) => {
+                    all.costs.insert(next.clone(), next_cost);
+                    all.predecessors.insert(next.clone(), vec![node.clone()]);
+                    queue.push(Queued {
+                        priority: next_cost,
+                        cost: next_cost,
+                        node: next,
+                    });
+                }
+            }
+        }
+    }
+    all
+}
+
+/// An entry of the priority queue, which orders by priority only and pops
+/// the lowest first.
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.##...#E";
+
+    fn maze() -> (Grid<char>, Position, Position) {
+        let grid = Grid::from(MAZE);
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+        (grid, start, end)
+    }
+
+    fn open(grid: &Grid<char>, pos: &Position) -> Vec<Position> {
+        pos.neighbors()
+            .filter(|next| grid.get(next).is_some_and(|&c| c != '#'))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_shortest_path() {
+        let (grid, start, end) = maze();
+        let search = bfs(start, |pos| open(&grid, pos), |pos| *pos == end);
+        assert_eq!(search.goal(), Some(&end));
+        assert_eq!(search.goal_cost(), Some(12));
+        let path = search.path().unwrap();
+        assert_eq!(path.len(), 13);
+        assert_eq!(path[0], start);
+        assert!(path
+            .windows(2)
+            .all(|step| step[0].direction_to(&step[1]).is_some()));
+
+        let all = bfs(start, |pos| open(&grid, pos), |_| false);
+        assert_eq!(all.goal(), None);
+        assert_eq!(all.costs().len(), 22);
+        assert_eq!(all.cost(&Position::new(0, 7)), Some(11));
+        assert_eq!(all.order()[0], start);
+    }
+
+    #[test]
+    fn unreachable() {
+        let search = bfs(0, |&n: &u32| (n < 5).then_some(n + 1), |&n| n == 10);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.path(), None);
+        assert_eq!(search.path_to(&5), Some(vec![0, 1, 2, 3, 4, 5]));
+        assert_eq!(search.path_to(&6), None);
+    }
+
+    #[test]
+    fn dfs_order() {
+        let edges = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (3, vec![4, 5]), (4, vec![])]);
+        let successors = |n: &u8| edges.get(n).cloned().unwrap_or_default();
+        let search = dfs(1, successors, |_| false);
+        assert_eq!(search.order(), [1, 2, 4, 3, 5]);
+        assert_eq!(search.cost(&4), Some(2));
+        assert_eq!(search.path_to(&5), Some(vec![1, 3, 5]));
+
+        let search = dfs(1, successors, |&n| n == 4);
+        assert_eq!(search.order(), [1, 2, 4]);
+        assert_eq!(search.path(), Some(vec![1, 2, 4]));
+    }
+
+    #[test]
+    fn dijkstra_weighted() {
+        // The direct edge is more expensive than the detour.
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('d', 2)]),
+            ('d', vec![('b', 1)]),
+            ('b', vec![('e', 1)]),
+        ]);
+        let successors = |n: &char| edges.get(n).cloned().unwrap_or_default();
+        let search = dijkstra('a', successors, |&n| n == 'e');
+        assert_eq!(search.goal_cost(), Some(6));
+        assert_eq!(search.path(), Some(vec!['a', 'c', 'd', 'b', 'e']));
+        assert_eq!(search.cost(&'b'), Some(5));
+    }
+
+    #[test]
+    fn astar_expands_less() {
+        let (grid, start, end) = maze();
+        let successors = |pos: &Position| open(&grid, pos).into_iter().map(|next| (next, 1));
+        let distance = |pos: &Position| pos.row.abs_diff(end.row) + pos.col.abs_diff(end.col);
+
+        let plain = dijkstra(start, successors, |pos| *pos == end);
+        let guided = astar(start, successors, distance, |pos| *pos == end);
+        assert_eq!(guided.goal_cost(), plain.goal_cost());
+        assert_eq!(guided.goal_cost(), Some(12));
+        assert!(guided.order().len() < plain.order().len());
+    }
+
+    #[test]
+    fn all_paths_in_open_grid() {
+        let end = Position::new(2, 2);
+        let successors = |pos: &Position| {
+            pos.neighbors_contained(end)
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let all = dijkstra_all(Position::new(0, 0), successors, |pos| *pos == end);
+        assert_eq!(all.goals(), [end]);
+        assert_eq!(all.goal_cost(), Some(4));
+        assert_eq!(all.count_paths(), 6);
+        assert_eq!(all.predecessors(&Position::new(1, 1)).len(), 2);
+        assert_eq!(all.nodes().len(), 9);
+
+        let paths: HashSet<Vec<Position>> = all.paths().collect();
+        assert_eq!(paths.len(), 6);
+        assert!(paths
+            .iter()
+            .all(|path| path.len() == 5 && path[0] == Position::new(0, 0) && path[4] == end));
+    }
+
+    #[test]
+    fn all_paths_only_optimal() {
+        let (grid, start, end) = maze();
+        let successors = |pos: &Position| open(&grid, pos).into_iter().map(|next| (next, 1));
+        let all = dijkstra_all(start, successors, |pos| *pos == end);
+        assert_eq!(all.goal_cost(), Some(12));
+        // The way along the top row is one step longer.
+        assert_eq!(all.count_paths(), 1);
+        assert_eq!(all.nodes().len(), 13);
+        assert!(!all.nodes().contains(&Position::new(0, 7)));
+    }
+
+    #[test]
+    fn all_paths_several_goals() {
+        // 'a' reaches both goals 'x' and 'y' at cost 2, 'z' costs more.
+        let edges = HashMap::from([
+            ('a', vec![('b', 1), ('c', 1), ('z', 1)]),
+            ('b', vec![('x', 1), ('y', 1)]),
+            ('c', vec![('x', 1)]),
+            ('z', vec![('q', 5)]),
+        ]);
+        let successors = |n: &char| edges.get(n).cloned().unwrap_or_default();
+        let all = dijkstra_all('a', successors, |&n| "xyz".contains(n) && n != 'z');
+        assert_eq!(all.goals().len(), 2);
+        assert_eq!(all.count_paths(), 3);
+        assert_eq!(all.paths().count(), 3);
+        assert_eq!(all.nodes(), HashSet::from(['a', 'b', 'c', 'x', 'y']));
+
+        let none = dijkstra_all('a', successors, |&n| n == 'w');
+        assert_eq!(none.goal_cost(), None);
+        assert_eq!(none.count_paths(), 0);
+        assert_eq!(none.paths().next(), None);
+    }
+
+    #[test]
+    fn paths_are_lazy() {
+        let end = Position::new(40, 40);
+        let successors = |pos: &Position| {
+            pos.neighbors_contained(end)
+                .map(|next| (next, 1u32))
+                .collect::<Vec<_>>()
+        };
+        let all = dijkstra_all(Position::new(0, 0), successors, |pos| *pos == end);
+        // 80 choose 40
+        assert_eq!(all.count_paths(), 107_507_208_733_336_176_461_620);
+        let first: Vec<_> = all.paths().take(3).collect();
+        assert_eq!(first.len(), 3);
+        assert!(first.iter().all(|path| path.len() == 81));
+    }
+}
@@ -0,0 +1,318 @@
+use crate::bench::{self, Timings};
+use std::fmt;
+
+/// Common interface of every day, so that a single runner can dispatch to any
+/// of them.
+///
+/// The input is parsed once and then handed to both parts, so the parts
+/// should not parse it again. Otherwise the parse stage of the benchmarks
+/// only times a no-op.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer;
+
+    fn part2(_parsed: &Self::Parsed<'_>) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl TryFrom<u8> for Part {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(value),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// The answer of a single part.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    Unsolved,
+}
+
+impl Answer {
+    pub fn is_solved(&self) -> bool {
+        *self != Answer::Unsolved
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Int(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Answer::Unsolved, Into::into)
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+/// A type-erased [`Solution`], as created by [`register!`](crate::register).
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// Directory of the crate the solution lives in.
+    pub dir: &'static str,
+    solve: fn(&str, &[Part]) -> Vec<Answer>,
+    bench: fn(&str, &bench::Config) -> Timings,
+}
+
+impl Entry {
+    pub const fn new<S: Solution>(dir: &'static str) -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            dir,
+            solve: solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+
+    /// Parses the input once and returns the answers of the given parts in the
+    /// same order.
+    pub fn solve(&self, input: &str, parts: &[Part]) -> Vec<Answer> {
+        (self.solve)(input, parts)
+    }
+
+    /// Times parsing and both parts, see [`bench::bench`].
+    pub fn bench(&self, input: &str, config: &bench::Config) -> Timings {
+        (self.bench)(input, config)
+    }
+}
+
+impl fmt::Debug for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Entry")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .field("dir", &self.dir)
+            .finish()
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[Part]) -> Vec<Answer> {
+    let parsed = S::parse(input);
+    parts
+        .iter()
+        .map(|part| match part {
+            Part::One => S::part1(&parsed),
+            Part::Two => S::part2(&parsed),
+        })
+        .collect()
+}
+
+/// All registered solutions, as created by [`registry!`](crate::registry).
+pub struct Registry {
+    entries: &'static [Entry],
+}
+
+impl Registry {
+    pub const fn new(entries: &'static [Entry]) -> Self {
+        Self { entries }
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day)
+    }
+
+    /// Returns an iterator over the solutions of a year, ordered by day.
+    pub fn year(&self, year: u16) -> impl Iterator<Item = &Entry> {
+        let mut entries: Vec<&Entry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.year == year)
+            .collect();
+        entries.sort_by_key(|entry| entry.day);
+        entries.into_iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Entry> {
+        self.entries.iter()
+    }
+}
+
+/// Registers a [`Solution`] of the calling crate by defining a public
+/// `SOLUTION` constant, which can then be collected with
+/// [`registry!`](crate::registry).
+///
+/// ```ignore
+/// pub struct Day01;
+///
+/// impl Solution for Day01 { ... }
+///
+/// utils::register!(Day01);
+/// ```
+#[macro_export]
+macro_rules! register {
+    ($solution:ty) => {
+        pub const SOLUTION: $crate::solution::Entry =
+            $crate::solution::Entry::new::<$solution>(env!("CARGO_MANIFEST_DIR"));
+    };
+}
+
+/// Collects the solutions of the given crates, which registered them with
+/// [`register!`](crate::register), into a [`Registry`].
+///
+/// ```ignore
+/// const REGISTRY: Registry = utils::registry![day01, day02, day03];
+/// ```
+#[macro_export]
+macro_rules! registry {
+    ($($krate:ident),* $(,)?) => {
+        $crate::solution::Registry::new(&[$($krate::SOLUTION),*])
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Parsed<'a> = Vec<&'a str>;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input.split(',').collect()
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+            parsed
+                .iter()
+                .map(|n| n.parse::<u32>().unwrap())
+                .sum::<u32>()
+                .into()
+        }
+    }
+
+    struct Join;
+
+    impl Solution for Join {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 2;
+
+        type Parsed<'a> = &'a str;
+
+        fn parse(input: &str) -> Self::Parsed<'_> {
+            input
+        }
+
+        fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+            parsed.replace(',', "").into()
+        }
+    }
+
+    const REGISTRY: Registry = Registry::new(&[Entry::new::<Join>(""), Entry::new::<Sum>("")]);
+
+    #[test]
+    fn answer_from() {
+        assert_eq!(Answer::from(42usize), Answer::Int(42));
+        assert_eq!(Answer::from(-7i64), Answer::Int(-7));
+        assert_eq!(Answer::from("a,b"), Answer::Text("a,b".to_string()));
+        assert_eq!(Answer::from(Some(3u8)), Answer::Int(3));
+        assert_eq!(Answer::from(None::<String>), Answer::Unsolved);
+    }
+
+    #[test]
+    fn answer_display() {
+        assert_eq!(Answer::Int(-12).to_string(), "-12");
+        assert_eq!(Answer::Text("7,4,2".to_string()).to_string(), "7,4,2");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn part_try_from() {
+        assert_eq!(Part::try_from(1), Ok(Part::One));
+        assert_eq!(Part::try_from(2), Ok(Part::Two));
+        assert_eq!(Part::try_from(3), Err(3));
+    }
+
+    #[test]
+    fn entry_solve() {
+        let entry = REGISTRY.get(2000, 1).unwrap();
+        assert_eq!(
+            entry.solve("1,2,3", &Part::ALL),
+            [Answer::Int(6), Answer::Unsolved]
+        );
+        assert_eq!(
+            entry.solve("1,2,3", &[Part::Two, Part::One]),
+            [Answer::Unsolved, Answer::Int(6)]
+        );
+    }
+
+    #[test]
+    fn registry_year() {
+        let days: Vec<u8> = REGISTRY.year(2000).map(|entry| entry.day).collect();
+        assert_eq!(days, vec![1, 2]);
+        assert_eq!(REGISTRY.year(2001).count(), 0);
+        assert!(REGISTRY.get(2000, 3).is_none());
+    }
+}
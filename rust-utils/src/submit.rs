@@ -0,0 +1,493 @@
+use crate::fetch::{write_atomic, Fetcher, HttpClient};
+use crate::puzzle;
+use crate::solution::{Answer, Part};
+use crate::{Error, Result};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What the site said about a submitted answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// Answers were submitted too quickly, nothing was checked.
+    Wait(Duration),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    /// A response that is not understood, with the text of the page.
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Reads the verdict from the page returned after submitting.
+    pub fn parse(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if text.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::Wait(parse_wait(&text).unwrap_or(Duration::from_secs(60)))
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unknown(text.split_whitespace().collect::<Vec<_>>().join(" "))
+        }
+    }
+
+    /// Whether the verdict says something about the answer and is logged.
+    fn is_guess(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        )
+    }
+
+    fn from_log(s: &str) -> Option<Self> {
+        match s {
+            "correct" => Some(Verdict::Correct),
+            "too high" => Some(Verdict::TooHigh),
+            "too low" => Some(Verdict::TooLow),
+            "wrong" => Some(Verdict::Wrong),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(wait) => write!(f, "wait {}s", wait.as_secs()),
+            Verdict::WrongLevel => write!(f, "wrong level, already solved?"),
+            Verdict::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+/// Returns how long the page returned after submitting asks to wait before
+/// the next answer, both after a wrong answer and after submitting too
+/// quickly.
+pub fn wait_hint(html: &str) -> Option<Duration> {
+    let text = article_text(html);
+    parse_wait(&text).or_else(|| parse_penalty(&text))
+}
+
+/// Returns the text of the article with the response, or of the whole page
+/// if there is none.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, article)| article.split_once('>'))
+        .and_then(|(_, article)| article.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    puzzle::text(article)
+}
+
+/// Parses e.g. "Please wait one minute before trying again." or "please wait
+/// 5 minutes before trying again.", as said after wrong answers.
+fn parse_penalty(text: &str) -> Option<Duration> {
+    let text = text.to_lowercase();
+    let (_, rest) = text.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let n: u64 = match words.next()? {
+        "one" => 1,
+        n => n.parse().ok()?,
+    };
+    let unit = words.next()?.trim_end_matches('s');
+    match unit {
+        "hour" => Some(Duration::from_secs(n * 3600)),
+        "minute" => Some(Duration::from_secs(n * 60)),
+        "second" => Some(Duration::from_secs(n)),
+        _ => None,
+    }
+}
+
+/// Parses e.g. "You have 1m 3s left to wait."
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+    wait.split_whitespace()
+        .map(|token| {
+            let (n, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+            let n: u64 = n.parse().ok()?;
+            match unit {
+                "h" => Some(n * 3600),
+                "m" => Some(n * 60),
+                "s" => Some(n),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Whether a submission went to the site or was answered from the log.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Submitted(Verdict),
+    /// Nothing was sent, because the verdict already follows from earlier
+    /// guesses.
+    Known(Verdict),
+    /// Nothing was sent, because the site asked to wait this much longer
+    /// after an earlier submission.
+    Waiting(Duration),
+}
+
+/// A logged guess.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// All guesses of a day, stored as `<part>\t<verdict>\t<answer>` lines, and
+/// until when the site asked to wait, stored as a `wait\t<unix time>` line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GuessLog {
+    pub guesses: Vec<Guess>,
+    pub wait_until: Option<SystemTime>,
+}
+
+impl GuessLog {
+    pub fn parse(log: &str) -> Self {
+        let wait_until = log.lines().find_map(|line| {
+            let secs = line.strip_prefix("wait\t")?.parse().ok()?;
+            Some(UNIX_EPOCH + Duration::from_secs(secs))
+        });
+        let guesses = log
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let part = fields.next()?.parse::<u8>().ok()?;
+                Some(Guess {
+                    part: Part::try_from(part).ok()?,
+                    verdict: Verdict::from_log(fields.next()?)?,
+                    answer: fields.next()?.to_string(),
+                })
+            })
+            .collect();
+        Self {
+            guesses,
+            wait_until,
+        }
+    }
+
+    /// Returns how much longer the site wants to be left alone at `now`.
+    pub fn remaining_wait(&self, now: SystemTime) -> Option<Duration> {
+        self.wait_until?
+            .duration_since(now)
+            .ok()
+            .filter(|wait| !wait.is_zero())
+    }
+
+    /// Returns the verdict that follows from earlier guesses: the answer once
+    /// the part is solved, the same verdict for a repeated guess, and too
+    /// high or too low for numbers beyond an earlier bound.
+    pub fn known(&self, part: Part, answer: &str) -> Option<Verdict> {
+        let guesses = || self.guesses.iter().filter(move |guess| guess.part == part);
+        if let Some(correct) = guesses().find(|guess| guess.verdict == Verdict::Correct) {
+            return Some(if correct.answer == answer {
+                Verdict::Correct
+            } else {
+                Verdict::Wrong
+            });
+        }
+        if let Some(guess) = guesses().find(|guess| guess.answer == answer) {
+            return Some(guess.verdict.clone());
+        }
+
+        let n: i128 = answer.parse().ok()?;
+        guesses().find_map(|guess| {
+            let bound: i128 = guess.answer.parse().ok()?;
+            match guess.verdict {
+                Verdict::TooHigh if n >= bound => Some(Verdict::TooHigh),
+                Verdict::TooLow if n <= bound => Some(Verdict::TooLow),
+                _ => None,
+            }
+        })
+    }
+}
+
+impl fmt::Display for GuessLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for guess in &self.guesses {
+            writeln!(f, "{}\t{}\t{}", guess.part, guess.verdict, guess.answer)?;
+        }
+        if let Some(wait_until) = self.wait_until {
+            let secs = wait_until.duration_since(UNIX_EPOCH).unwrap_or_default();
+            writeln!(f, "wait\t{}", secs.as_secs())?;
+        }
+        Ok(())
+    }
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    pub fn guesses_path(&self, year: u16, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("guesses.tsv")
+    }
+
+    pub fn guesses(&self, year: u16, day: u8) -> Result<GuessLog> {
+        let path = self.guesses_path(year, day);
+        match fs::read_to_string(&path) {
+            Ok(log) => Ok(GuessLog::parse(&log)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(GuessLog::default()),
+            Err(source) => Err(Error::Io { path, source }),
+        }
+    }
+
+    /// Submits an answer, unless its verdict already follows from the guess
+    /// log of the day or the site asked to wait, and logs the verdict and how
+    /// long to wait before the next answer.
+    ///
+    /// Returns [`Error::InvalidAnswer`] for answers with tabs or line breaks.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<Outcome> {
+        self.submit_at(year, day, part, answer, SystemTime::now())
+    }
+
+    fn submit_at(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        answer: &Answer,
+        now: SystemTime,
+    ) -> Result<Outcome> {
+        let answer = answer.to_string();
+        if answer.contains(['\n', '\r', '\t']) {
+            return Err(Error::InvalidAnswer(answer));
+        }
+        let mut log = self.guesses(year, day)?;
+        if let Some(verdict) = log.known(part, &answer) {
+            return Ok(Outcome::Known(verdict));
+        }
+        if let Some(wait) = log.remaining_wait(now) {
+            return Ok(Outcome::Waiting(wait));
+        }
+
+        let url = format!("{}/{}/day/{}/answer", self.base_url(), year, day);
+        let level = part.to_string();
+        let page = self.post(&url, &[("level", &level), ("answer", &answer)])?;
+        let verdict = Verdict::parse(&page);
+
+        let wait = match verdict {
+            Verdict::Wait(wait) => Some(wait),
+            _ => wait_hint(&page),
+        };
+        if let Some(wait) = wait {
+            log.wait_until = Some(now + wait);
+        }
+        if verdict.is_guess() {
+            log.guesses.push(Guess {
+                part,
+                answer,
+                verdict: verdict.clone(),
+            });
+        }
+        if wait.is_some() || verdict.is_guess() {
+            write_atomic(&self.guesses_path(year, day), &log.to_string())?;
+        }
+        Ok(Outcome::Submitted(verdict))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::{FakeClient, Request, Response};
+    use std::env;
+
+    const URL: &str = "https://adventofcode.com/2024/day/17/answer";
+
+    fn page(text: &str) -> Response {
+        Response::ok(format!(
+            "<main>\n<article><p>{}</p></article>\n</main>",
+            text
+        ))
+    }
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("utils-submit-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn parse_verdicts() {
+        let verdict = |text: &str| Verdict::parse(&page(text).body);
+        assert_eq!(
+            verdict("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."),
+            Verdict::Correct
+        );
+        assert_eq!(
+            verdict(
+                "That's not the right answer; your answer is too high.  Please wait one minute."
+            ),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            verdict("That's not the right answer.  If you're stuck, make sure you're using the full input data."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 3s left to wait. <a href=\"/2024/day/17\">[Return to Day 17]</a>"),
+            Verdict::Wait(Duration::from_secs(63))
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level.  Did you already complete it?"),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            verdict("Something  <em>else</em>"),
+            Verdict::Unknown("Something else".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_wait_hints() {
+        let hint = |text: &str| wait_hint(&page(text).body);
+        assert_eq!(
+            hint("That's not the right answer; your answer is too low.  Please wait one minute before trying again."),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            hint("That's not the right answer.  Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes before trying again."),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            hint("You gave an answer too recently.  You have 45s left to wait."),
+            Some(Duration::from_secs(45))
+        );
+        assert_eq!(hint("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn known_verdicts() {
+        let log = GuessLog::parse(
+            "2\ttoo high\t500\n2\ttoo low\t100\n2\twrong\t300\n1\tcorrect\t7,4,2\n",
+        );
+        assert_eq!(log.guesses.len(), 4);
+        assert_eq!(log.known(Part::Two, "300"), Some(Verdict::Wrong));
+        assert_eq!(log.known(Part::Two, "600"), Some(Verdict::TooHigh));
+        assert_eq!(log.known(Part::Two, "100"), Some(Verdict::TooLow));
+        assert_eq!(log.known(Part::Two, "200"), None);
+        assert_eq!(log.known(Part::One, "7,4,2"), Some(Verdict::Correct));
+        assert_eq!(log.known(Part::One, "7,4,3"), Some(Verdict::Wrong));
+        assert_eq!(GuessLog::parse(&log.to_string()), log);
+
+        let log = GuessLog::parse("1\twrong\t5\nwait\t100\n");
+        assert_eq!(log.guesses.len(), 1);
+        let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+        assert_eq!(log.remaining_wait(at(40)), Some(Duration::from_secs(60)));
+        assert_eq!(log.remaining_wait(at(100)), None);
+        assert_eq!(GuessLog::parse(&log.to_string()), log);
+    }
+
+    #[test]
+    fn submit_logs_guesses() {
+        let client = FakeClient::new()
+            .with_response(
+                URL,
+                page("That's not the right answer; your answer is too high."),
+            )
+            .with_response(
+                URL,
+                page("You gave an answer too recently; You have 30s left to wait."),
+            )
+            .with_response(URL, page("That's the right answer!"));
+        let fetcher = Fetcher::new(&client, cache_dir("log")).with_session("abc");
+        let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let submit = |n: i128, secs: u64| {
+            let now = start + Duration::from_secs(secs);
+            fetcher
+                .submit_at(2024, 17, Part::Two, &Answer::Int(n), now)
+                .unwrap()
+        };
+
+        assert_eq!(submit(500, 0), Outcome::Submitted(Verdict::TooHigh));
+        assert_eq!(submit(600, 0), Outcome::Known(Verdict::TooHigh));
+        assert_eq!(
+            submit(400, 0),
+            Outcome::Submitted(Verdict::Wait(Duration::from_secs(30)))
+        );
+        assert_eq!(submit(400, 10), Outcome::Waiting(Duration::from_secs(20)));
+        assert_eq!(submit(400, 30), Outcome::Submitted(Verdict::Correct));
+        assert_eq!(submit(400, 30), Outcome::Known(Verdict::Correct));
+
+        assert_eq!(client.urls(), vec![URL; 3]);
+        assert_eq!(
+            client.requests()[0],
+            Request {
+                url: URL.to_string(),
+                form: Some(vec![
+                    ("level".to_string(), "2".to_string()),
+                    ("answer".to_string(), "500".to_string())
+                ]),
+            }
+        );
+        assert_eq!(
+            fetcher.guesses(2024, 17).unwrap().to_string(),
+            "2\ttoo high\t500\n2\tcorrect\t400\nwait\t1700000030\n"
+        );
+    }
+
+    #[test]
+    fn submit_waits_after_wrong_answers() {
+        let client = FakeClient::new()
+            .with_response(
+                URL,
+                page("That's not the right answer.  Please wait one minute before trying again."),
+            )
+            .with_response(URL, page("That's the right answer!"));
+        let fetcher = Fetcher::new(&client, cache_dir("penalty")).with_session("abc");
+        let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let submit = |answer: &str, secs: u64| {
+            let now = start + Duration::from_secs(secs);
+            fetcher.submit_at(2024, 17, Part::One, &Answer::from(answer), now)
+        };
+
+        assert_eq!(
+            submit("7,4,2", 0).unwrap(),
+            Outcome::Submitted(Verdict::Wrong)
+        );
+        assert_eq!(
+            submit("7,4,3", 20).unwrap(),
+            Outcome::Waiting(Duration::from_secs(40))
+        );
+        assert_eq!(
+            submit("7,4,3", 60).unwrap(),
+            Outcome::Submitted(Verdict::Correct)
+        );
+        assert_eq!(client.urls(), vec![URL; 2]);
+
+        let err = submit("7\n4", 60).unwrap_err();
+        assert!(matches!(err, Error::InvalidAnswer(_)));
+        assert!(matches!(
+            submit("7\t4", 60).unwrap_err(),
+            Error::InvalidAnswer(_)
+        ));
+        assert_eq!(
+            fetcher.guesses(2024, 17).unwrap().to_string(),
+            "1\twrong\t7,4,2\n1\tcorrect\t7,4,3\nwait\t1700000060\n"
+        );
+    }
+}